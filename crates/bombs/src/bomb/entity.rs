@@ -5,9 +5,7 @@ use serde::{Deserialize, Serialize};
 /// Grid position represented by (x, y).
 pub type Position = (u16, u16);
 
-/// Unique identifier for a bomb.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct BombId(pub u32);
+pub use state::BombId;
 
 /// Bomb instance with properties relevant for chain reactions.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,3 +46,16 @@ impl Bomb {
         }
     }
 }
+
+impl From<&state::Bomb> for Bomb {
+    fn from(bomb: &state::Bomb) -> Self {
+        // Any bomb on the grid can be kicked; whether the kicker may do so is
+        // up to the engine.
+        Self {
+            pierce: bomb.pierce,
+            remote: bomb.remote,
+            kickable: true,
+            ..Self::new(bomb.id, bomb.owner, bomb.position, bomb.timer, bomb.power)
+        }
+    }
+}
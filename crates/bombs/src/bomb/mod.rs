@@ -74,20 +74,46 @@ impl BombManager {
         }
     }
 
+    /// Removes a bomb from the manager, returning it if it was present.
+    pub fn remove_bomb(&mut self, id: BombId) -> Option<Bomb> {
+        self.timers.remove(&id);
+        self.remote.disarm(id);
+        self.bombs.remove(&id)
+    }
+
+    /// Removes every bomb for which `keep` returns `false`.
+    pub fn retain(&mut self, mut keep: impl FnMut(&Bomb) -> bool) {
+        let dropped: Vec<_> = self
+            .bombs
+            .values()
+            .filter(|b| !keep(b))
+            .map(|b| b.id)
+            .collect();
+        for id in dropped {
+            self.remove_bomb(id);
+        }
+    }
+
+    /// Looks up a managed bomb by id.
+    pub fn bomb(&self, id: BombId) -> Option<&Bomb> {
+        self.bombs.get(&id)
+    }
+
     /// Returns the current bomb chains based on spatial relationships.
     pub fn calculate_chain_reactions(&self) -> Vec<BombChain> {
         find_bomb_chains(&self.bombs)
     }
 
-    /// Calculates the explosion for a given bomb on a grid with `walls`.
+    /// Calculates the explosion for a given bomb on a grid with `walls` and soft `crates`.
     pub fn calculate_explosion(
         &self,
         id: BombId,
         size: (u16, u16),
         walls: &HashSet<entity::Position>,
+        crates: &HashSet<entity::Position>,
     ) -> Result<Explosion, BombError> {
         let bomb = self.bombs.get(&id).ok_or(BombError::MissingBomb(id))?;
-        Ok(Explosion::from_bomb(bomb, size, walls))
+        Ok(Explosion::from_bomb(bomb, size, walls, crates))
     }
 }
 
@@ -107,11 +133,22 @@ mod tests {
         assert_eq!(chains.len(), 1);
 
         let explosion = mgr
-            .calculate_explosion(b1.id, (5, 5), &HashSet::new())
+            .calculate_explosion(b1.id, (5, 5), &HashSet::new(), &HashSet::new())
             .unwrap();
         assert!(explosion.affected_cells.contains(&b2.position));
     }
 
+    #[test]
+    fn bombs_of_one_owner_are_kept_apart() {
+        let mut mgr = BombManager::new();
+        mgr.add_bomb(Bomb::from(&state::Bomb::new(BombId(1), 7, (0, 0), 3, 1)));
+        mgr.add_bomb(Bomb::from(&state::Bomb::new(BombId(2), 7, (4, 4), 3, 1)));
+        assert_eq!(mgr.bomb(BombId(1)).unwrap().position, (0, 0));
+        assert_eq!(mgr.remove_bomb(BombId(2)).unwrap().position, (4, 4));
+        assert!(mgr.bomb(BombId(2)).is_none());
+        assert!(mgr.bomb(BombId(1)).is_some());
+    }
+
     #[test]
     fn ticking_triggers_bomb() {
         let mut mgr = BombManager::new();
@@ -1,14 +1,18 @@
 //! Chain reaction handling for explosions.
 
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
 use state::grid::GameGrid;
 
-use crate::bomb::entity::Position;
-use crate::explosion::Explosion;
+use crate::bomb::entity::{BombId, Position};
+use crate::explosion::{BlastField, Explosion};
 use serde::{Deserialize, Serialize};
 
 /// Result of processing a chain reaction.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChainReaction {
+    /// Bombs that went off, starting with the trigger.
+    pub bombs: Vec<BombId>,
     /// Positions affected by the chain reaction.
     pub positions: Vec<Position>,
 }
@@ -23,25 +27,91 @@ impl ChainReactionHandler {
         Self
     }
 
-    /// Process potential chain reactions given explosions and mutate the grid.
+    /// Follow `explosions` through every bomb on `grid` their blasts reach.
+    ///
+    /// A bomb caught in a blast goes off in the same tick and may set off
+    /// further bombs in turn. Each bomb belongs to exactly one reaction, so
+    /// an explosion already swept up by an earlier one adds no reaction of
+    /// its own. Blasts are traced against the crates present before any of
+    /// them went off.
     pub fn process_chain_reactions(
         &self,
-        _explosions: Vec<Explosion>,
-        _grid: &mut GameGrid,
+        explosions: Vec<Explosion>,
+        grid: &GameGrid,
     ) -> Vec<ChainReaction> {
-        Vec::new()
+        let field = BlastField::from_grid(grid);
+        let mut detonated = HashSet::new();
+        let mut reactions = Vec::new();
+        for explosion in explosions {
+            if !detonated.insert(explosion.position) {
+                continue;
+            }
+            let mut bombs = vec![explosion.bomb_id];
+            let mut positions = BTreeSet::new();
+            let mut queue = VecDeque::from([explosion]);
+            while let Some(current) = queue.pop_front() {
+                for tile in field.pattern(&current) {
+                    positions.insert(tile);
+                    let Some(bomb) = grid.bombs().iter().find(|b| b.position == tile) else {
+                        continue;
+                    };
+                    if detonated.insert(tile) {
+                        bombs.push(bomb.id);
+                        queue.push_back(Explosion::from(bomb));
+                    }
+                }
+            }
+            reactions.push(ChainReaction {
+                bombs,
+                positions: positions.into_iter().collect(),
+            });
+        }
+        reactions
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::explosion::ExplosionCalculator;
 
     #[test]
     fn handler_returns_empty() {
         let handler = ChainReactionHandler::new();
-        let mut grid = GameGrid::new(1, 1);
-        let reactions = handler.process_chain_reactions(Vec::new(), &mut grid);
+        let grid = GameGrid::new(1, 1);
+        let reactions = handler.process_chain_reactions(Vec::new(), &grid);
         assert!(reactions.is_empty());
     }
+
+    #[test]
+    fn blasts_set_off_bombs_in_range() {
+        let mut grid = GameGrid::from_ascii("#######\n#.....#\n#.#.#.#\n#######").unwrap();
+        grid.add_bomb(state::Bomb::new(BombId(1), 0, (1, 1), 0, 2));
+        grid.add_bomb(state::Bomb::new(BombId(2), 1, (3, 1), 4, 1));
+        grid.add_bomb(state::Bomb::new(BombId(3), 1, (3, 2), 4, 1));
+        grid.add_bomb(state::Bomb::new(BombId(4), 2, (5, 2), 4, 1));
+        let explosions = ExplosionCalculator::new().calculate_explosions(&grid);
+        let reactions = ChainReactionHandler::new().process_chain_reactions(explosions, &grid);
+
+        assert_eq!(reactions.len(), 1);
+        assert_eq!(reactions[0].bombs, vec![BombId(1), BombId(2), BombId(3)]);
+        assert!(reactions[0].positions.contains(&(4, 1)));
+        assert!(!reactions[0].positions.contains(&(5, 1)));
+    }
+
+    #[test]
+    fn triggers_swept_up_by_earlier_reactions_are_merged() {
+        let mut grid = GameGrid::new(5, 1);
+        for x in 0..5 {
+            grid.set_tile(x, 0, state::Tile::Empty);
+        }
+        grid.add_bomb(state::Bomb::new(BombId(1), 0, (0, 0), 0, 1));
+        grid.add_bomb(state::Bomb::new(BombId(2), 1, (1, 0), 0, 1));
+        grid.add_bomb(state::Bomb::new(BombId(3), 2, (4, 0), 0, 1));
+        let explosions = ExplosionCalculator::new().calculate_explosions(&grid);
+        let reactions = ChainReactionHandler::new().process_chain_reactions(explosions, &grid);
+
+        let bombs: Vec<_> = reactions.iter().map(|r| r.bombs.clone()).collect();
+        assert_eq!(bombs, vec![vec![BombId(1), BombId(2)], vec![BombId(3)]]);
+    }
 }
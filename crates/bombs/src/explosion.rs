@@ -1,22 +1,90 @@
 //! Explosion calculation utilities.
 
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
-use state::grid::GameGrid;
+use state::grid::{GameGrid, Tile};
 
-use crate::bomb::entity::Position;
+use crate::bomb::entity::{BombId, Position};
+use crate::power::affected_tiles;
 
 /// Description of a bomb explosion.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Explosion {
+    /// Bomb that exploded.
+    pub bomb_id: BombId,
     /// Center position of the explosion.
     pub position: Position,
     /// Blast radius.
     pub radius: u32,
+    /// Whether the blast passes through soft crates.
+    pub pierce: bool,
+}
+
+impl From<&state::Bomb> for Explosion {
+    fn from(bomb: &state::Bomb) -> Self {
+        Self {
+            bomb_id: bomb.id,
+            position: bomb.position,
+            radius: bomb.power as u32,
+            pierce: bomb.pierce,
+        }
+    }
 }
 
 /// Collection of tiles affected by an explosion.
 pub type BlastPattern = Vec<Position>;
 
+/// Walls and soft crates of a grid as seen by blast rays.
+#[derive(Debug, Clone, Default)]
+pub struct BlastField {
+    size: (u16, u16),
+    walls: HashSet<Position>,
+    crates: HashSet<Position>,
+}
+
+impl BlastField {
+    /// Capture the obstacles currently on `grid`.
+    pub fn from_grid(grid: &GameGrid) -> Self {
+        let mut field = Self {
+            size: (grid.width() as u16, grid.height() as u16),
+            ..Self::default()
+        };
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                let pos = (x as u16, y as u16);
+                match grid.tile(x, y) {
+                    Some(Tile::Wall) => {
+                        field.walls.insert(pos);
+                    }
+                    Some(Tile::SoftCrate) => {
+                        field.crates.insert(pos);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        field
+    }
+
+    /// Tiles reached by `explosion`, sorted by position.
+    pub fn pattern(&self, explosion: &Explosion) -> BlastPattern {
+        let power = explosion.radius.min(u8::MAX as u32) as u8;
+        let mut tiles: Vec<_> = affected_tiles(
+            explosion.position,
+            power,
+            self.size,
+            &self.walls,
+            &self.crates,
+            explosion.pierce,
+        )
+        .into_iter()
+        .collect();
+        tiles.sort();
+        tiles
+    }
+}
+
 /// Calculates explosions for bombs on the grid.
 #[derive(Default)]
 pub struct ExplosionCalculator;
@@ -27,9 +95,18 @@ impl ExplosionCalculator {
         Self
     }
 
-    /// Determine explosions for bombs currently on the grid.
-    pub fn calculate_explosions(&self, _grid: &GameGrid) -> Vec<Explosion> {
-        Vec::new()
+    /// Determine explosions for bombs on the grid whose timers have run out.
+    pub fn calculate_explosions(&self, grid: &GameGrid) -> Vec<Explosion> {
+        grid.bombs()
+            .iter()
+            .filter(|b| b.is_exploding())
+            .map(Explosion::from)
+            .collect()
+    }
+
+    /// Tiles reached by `explosion` on `grid`.
+    pub fn blast_pattern(&self, explosion: &Explosion, grid: &GameGrid) -> BlastPattern {
+        BlastField::from_grid(grid).pattern(explosion)
     }
 }
 
@@ -43,4 +120,27 @@ mod tests {
         let grid = GameGrid::new(1, 1);
         assert!(calc.calculate_explosions(&grid).is_empty());
     }
+
+    #[test]
+    fn blast_forms_a_cross_stopped_by_walls_and_crates() {
+        let mut grid = GameGrid::from_ascii(
+            "
+#######
+#.....#
+#.#.#.#
+#+....#
+#######
+",
+        )
+        .unwrap();
+        grid.add_bomb(state::Bomb::new(BombId(0), 0, (1, 1), 0, 3));
+        let calc = ExplosionCalculator::new();
+        let explosions = calc.calculate_explosions(&grid);
+        assert_eq!(explosions.len(), 1);
+        let pattern = calc.blast_pattern(&explosions[0], &grid);
+        assert_eq!(
+            pattern,
+            vec![(1, 1), (1, 2), (1, 3), (2, 1), (3, 1), (4, 1)]
+        );
+    }
 }
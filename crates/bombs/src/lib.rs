@@ -19,7 +19,7 @@ pub use bomb::{
 
 pub use analysis::{danger_tiles, is_safe, opportunity_tiles, safe_tiles};
 pub use chain::{ChainReaction, ChainReactionHandler};
-pub use explosion::{BlastPattern, Explosion, ExplosionCalculator};
+pub use explosion::{BlastField, BlastPattern, Explosion, ExplosionCalculator};
 pub use logic::{BombLogic, BombState};
 pub use placement::{
     BombPlacementStrategy, PlacementStrategy, SafePlacer, StrategicPlacer, TacticalPlacement,
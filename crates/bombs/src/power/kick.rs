@@ -5,17 +5,7 @@ use std::collections::HashSet;
 use crate::bomb::entity::{Bomb, Position};
 
 /// Directions in which a bomb can be kicked.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Direction {
-    /// Move one tile up.
-    Up,
-    /// Move one tile down.
-    Down,
-    /// Move one tile left.
-    Left,
-    /// Move one tile right.
-    Right,
-}
+pub use common::Direction;
 
 /// Attempts to kick `bomb` one tile in `dir`.
 /// Returns `true` if the bomb was moved.
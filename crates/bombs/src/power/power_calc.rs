@@ -6,14 +6,20 @@ use crate::bomb::entity::Position;
 
 /// Computes the set of tiles affected by a bomb at `position` with a given `power`.
 ///
+/// The blast travels up to `power` tiles in each of the four directions,
+/// forming a `+`. A ray stops before a wall and on the first soft crate it
+/// reaches, which is itself affected.
+///
 /// * `size` - Grid dimensions `(width, height)`.
 /// * `walls` - Positions that block blast propagation.
-/// * `pierce` - If true, blast continues past walls.
+/// * `crates` - Destructible positions that absorb the blast.
+/// * `pierce` - If true, rays carry on through soft crates.
 pub fn affected_tiles(
     position: Position,
     power: u8,
     size: (u16, u16),
     walls: &HashSet<Position>,
+    crates: &HashSet<Position>,
     pierce: bool,
 ) -> HashSet<Position> {
     let mut tiles = HashSet::new();
@@ -31,13 +37,12 @@ pub fn affected_tiles(
             }
             let pos = (x as u16, y as u16);
             if walls.contains(&pos) {
-                if pierce {
-                    continue;
-                } else {
-                    break;
-                }
+                break;
             }
             tiles.insert(pos);
+            if crates.contains(&pos) && !pierce {
+                break;
+            }
         }
     }
 
@@ -52,8 +57,20 @@ mod tests {
     fn power_respects_bounds_and_walls() {
         let mut walls = HashSet::new();
         walls.insert((2, 1));
-        let tiles = affected_tiles((1, 1), 3, (5, 5), &walls, false);
+        let tiles = affected_tiles((1, 1), 3, (5, 5), &walls, &HashSet::new(), false);
         assert!(tiles.contains(&(1, 4))); // down
         assert!(!tiles.contains(&(3, 1))); // blocked by wall
+        assert!(!tiles.contains(&(2, 2))); // no diagonals
+    }
+
+    #[test]
+    fn crates_absorb_rays_unless_pierced() {
+        let crates: HashSet<_> = [(2, 0), (3, 0)].into_iter().collect();
+        let tiles = affected_tiles((0, 0), 4, (5, 1), &HashSet::new(), &crates, false);
+        assert!(tiles.contains(&(2, 0)));
+        assert!(!tiles.contains(&(3, 0)));
+
+        let tiles = affected_tiles((0, 0), 4, (5, 1), &HashSet::new(), &crates, true);
+        assert!(tiles.contains(&(3, 0)) && tiles.contains(&(4, 0)));
     }
 }
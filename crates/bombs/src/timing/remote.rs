@@ -16,6 +16,11 @@ impl RemoteDetonator {
         self.armed.insert(id);
     }
 
+    /// Forgets a bomb without detonating it.
+    pub fn disarm(&mut self, id: BombId) {
+        self.armed.remove(&id);
+    }
+
     /// Attempts to detonate the given bomb. Returns `true` if the bomb was armed.
     pub fn detonate(&mut self, id: BombId) -> bool {
         self.armed.remove(&id)
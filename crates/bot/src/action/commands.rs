@@ -7,7 +7,7 @@ use bombs::{Direction, Position};
 pub enum Action {
     /// Move in a direction.
     Move(Direction),
-    /// Place a bomb at a position.
+    /// Place a bomb at a position; the engine places it with the rule timer.
     PlaceBomb {
         /// Grid position where the bomb should be placed.
         position: Position,
@@ -13,11 +13,17 @@ impl ActionExecutor for Action {
     fn execute(&self, grid: &mut GameGrid) -> ActionResult {
         match self {
             Action::PlaceBomb { position } => {
-                if grid.can_place_bomb(*position) {
-                    grid.place_bomb(*position);
-                    ActionResult::Success
-                } else {
-                    ActionResult::Failure("cannot place bomb here")
+                // Bombs are only ever placed by the engine, which owns the
+                // fuse; this checks the agent on the tile could place one.
+                match grid.agents().iter().find(|a| a.position == *position) {
+                    None => ActionResult::Failure("no agent to place the bomb"),
+                    Some(agent) if agent.bombs_left == 0 => {
+                        ActionResult::Failure("no bombs left")
+                    }
+                    Some(_) if !grid.can_place_bomb(*position) => {
+                        ActionResult::Failure("cannot place bomb here")
+                    }
+                    Some(_) => ActionResult::Success,
                 }
             }
             Action::Move(_) => ActionResult::Success,
@@ -29,13 +35,16 @@ impl ActionExecutor for Action {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use state::components::AgentState;
     use state::grid::{GameGrid, Tile};
 
     #[test]
     fn placing_bomb_success() {
         let action = Action::PlaceBomb { position: (0, 0) };
         let mut grid = GameGrid::new(1, 1);
+        grid.add_agent(AgentState::new(0, (0, 0)));
         assert!(matches!(action.execute(&mut grid), ActionResult::Success));
+        assert!(grid.bombs().is_empty());
     }
 
     #[test]
@@ -43,6 +52,25 @@ mod tests {
         let action = Action::PlaceBomb { position: (0, 0) };
         let mut grid = GameGrid::new(1, 1);
         grid.set_tile(0, 0, Tile::Wall);
+        grid.add_agent(AgentState::new(0, (0, 0)));
+        assert!(matches!(
+            action.execute(&mut grid),
+            ActionResult::Failure(_)
+        ));
+    }
+
+    #[test]
+    fn placing_bomb_needs_an_agent_with_bombs() {
+        let action = Action::PlaceBomb { position: (0, 0) };
+        let mut grid = GameGrid::new(1, 1);
+        assert!(matches!(
+            action.execute(&mut grid),
+            ActionResult::Failure(_)
+        ));
+        grid.add_agent(AgentState {
+            bombs_left: 0,
+            ..AgentState::new(0, (0, 0))
+        });
         assert!(matches!(
             action.execute(&mut grid),
             ActionResult::Failure(_)
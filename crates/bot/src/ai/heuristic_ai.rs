@@ -31,6 +31,10 @@ impl DecisionMaker<GridDelta, BotDecision> for HeuristicAI {
     fn decide(&mut self, snapshot: GridDelta) -> BotDecision {
         self.pipeline.decide(snapshot)
     }
+
+    fn observe(&mut self, snapshot: GridDelta) {
+        self.pipeline.observe(snapshot)
+    }
 }
 
 #[cfg(test)]
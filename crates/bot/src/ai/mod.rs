@@ -67,6 +67,14 @@ impl DecisionMaker<GridDelta, BotDecision> for SwitchingAI {
             AiType::Planning => self.planning.decide(snapshot),
         }
     }
+
+    fn observe(&mut self, snapshot: GridDelta) {
+        match self.current {
+            AiType::Heuristic => self.heuristic.observe(snapshot),
+            AiType::Reactive => self.reactive.observe(snapshot),
+            AiType::Planning => self.planning.observe(snapshot),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -28,6 +28,7 @@ pub struct AIDecisionPipeline {
 }
 
 impl AIDecisionPipeline {
+    /// Create a pipeline sharing the bot's goal manager, pathfinder and influence map.
     pub fn new(
         goal_manager: Arc<GoalManager>,
         pathfinder: Arc<Mutex<Pathfinder>>,
@@ -86,6 +87,12 @@ impl AIDecisionPipeline {
                     }
                 }
             }
+            GridDelta::UpdateAgent(agent) => {
+                self.agents.insert(agent.id, agent.clone());
+                if self.bot_id == Some(agent.id) {
+                    self.current_position = Some(agent.position);
+                }
+            }
             GridDelta::RemoveAgent(agent_id) => {
                 self.agents.remove(agent_id);
                 if let Some(bot_id) = self.bot_id {
@@ -99,6 +106,16 @@ impl AIDecisionPipeline {
                 // Update influence map with new bomb
                 self.update_influence_map_with_bombs();
             }
+            GridDelta::UpdateBomb(bomb) => {
+                if let Some(known) = self.bombs.iter_mut().find(|b| b.id == bomb.id) {
+                    *known = bomb.clone();
+                }
+                self.update_influence_map_with_bombs();
+            }
+            GridDelta::RemoveBomb(id) => {
+                self.bombs.retain(|bomb| bomb.id != *id);
+                self.update_influence_map_with_bombs();
+            }
             GridDelta::None => {}
         }
     }
@@ -208,29 +225,6 @@ impl AIDecisionPipeline {
         true
     }
 
-    /// Check if bot is currently in danger
-    fn is_in_danger(&self, pos: (u16, u16)) -> bool {
-        // Check influence map for danger
-        if let Ok(influence_guard) = self.influence_map.lock() {
-            let danger_value = influence_guard.danger_at(pos.0, pos.1).unwrap_or(0.0);
-            if danger_value > 0.0 {
-                return true; // Position is in danger zone
-            }
-        }
-        
-        // Also check for bombs that might explode soon as backup
-        for bomb in &self.bombs {
-            let distance = ((pos.0 as i32 - bomb.position.0 as i32).abs() + 
-                          (pos.1 as i32 - bomb.position.1 as i32).abs()) as u16;
-            
-            // If we're within bomb range and it might explode soon
-            if distance <= bomb.power.into() && bomb.timer <= 2 {
-                return true;
-            }
-        }
-        false
-    }
-
     /// Find escape direction when in danger
     fn escape_danger(&self, current_pos: (u16, u16)) -> BotDecision {
         use common::Direction;
@@ -410,6 +404,7 @@ impl AIDecisionPipeline {
                 }
                 BotDecision::Wait
             }
+            Action::Detonate => BotDecision::Detonate(None),
         }
     }
 
@@ -505,9 +500,8 @@ impl AIDecisionPipeline {
                 let index = check_y as usize * self.grid_width + check_x as usize;
                 if index < self.tiles.len() {
                     // If there's a destructible wall, this is a good spot
-                    match self.tiles[index] {
-                        state::Tile::SoftCrate => return true,
-                        _ => {}
+                    if self.tiles[index] == state::Tile::SoftCrate {
+                        return true;
                     }
                 }
             }
@@ -522,17 +516,7 @@ impl DecisionMaker<GridDelta, BotDecision> for AIDecisionPipeline {
     fn decide(&mut self, delta: GridDelta) -> BotDecision {
         // Update internal state
         self.tick_counter += 1;
-        
-        // Process the delta to update our internal state
-        self.process_delta(&delta);
-        
-        // Get bot ID from the delta if we don't have one yet
-        if self.bot_id.is_none() {
-            if let GridDelta::AddAgent(ref agent) = delta {
-                self.bot_id = Some(agent.id);
-                self.current_position = Some(agent.position);
-            }
-        }
+        self.observe(delta);
         
         let bot_id = match self.bot_id {
             Some(id) => id,
@@ -550,13 +534,13 @@ impl DecisionMaker<GridDelta, BotDecision> for AIDecisionPipeline {
             let fallback = self.fallback_random_decision();
             // Debug: Log when fallback is used
             if matches!(fallback, BotDecision::Move(_)) {
-                println!("🤖 Bot {} using fallback movement: {:?}", bot_id, fallback);
+                log::debug!("🤖 Bot {} using fallback movement: {:?}", bot_id, fallback);
             }
             fallback
         } else {
             // Debug: Log when goal-based decision is used
             if matches!(decision, BotDecision::Move(_)) {
-                println!("🎯 Bot {} using goal-based movement: {:?}", bot_id, decision);
+                log::debug!("🎯 Bot {} using goal-based movement: {:?}", bot_id, decision);
             }
             decision
         };
@@ -564,11 +548,23 @@ impl DecisionMaker<GridDelta, BotDecision> for AIDecisionPipeline {
         final_decision
     }
 
-    fn status(&self) -> Option<String> {
-        if let Some(active) = &self.planner.active_goal {
-            Some(format!("{:?}", active.goal.get_goal_type()))
-        } else {
-            None
+    fn observe(&mut self, delta: GridDelta) {
+        // Process the delta to update our internal state
+        self.process_delta(&delta);
+
+        // Get bot ID from the delta if we don't have one yet
+        if self.bot_id.is_none() {
+            if let GridDelta::AddAgent(ref agent) = delta {
+                self.bot_id = Some(agent.id);
+                self.current_position = Some(agent.position);
+            }
         }
     }
+
+    fn status(&self) -> Option<String> {
+        self.planner
+            .active_goal
+            .as_ref()
+            .map(|active| format!("{:?}", active.goal.get_goal_type()))
+    }
 }
@@ -3,6 +3,15 @@ pub trait DecisionMaker<Snap, Command>: Send + 'static {
     /// Produce a command for the provided snapshot.
     fn decide(&mut self, snapshot: Snap) -> Command;
 
+    /// Feed a snapshot without producing a command.
+    ///
+    /// Used in lock-step mode where updates arrive between tick requests. The
+    /// default implementation decides and discards the result so strategies
+    /// that only implement [`decide`](Self::decide) still see every update.
+    fn observe(&mut self, snapshot: Snap) {
+        let _ = self.decide(snapshot);
+    }
+
     /// Optional human-readable status string describing current intent/goal.
     fn status(&self) -> Option<String> {
         None
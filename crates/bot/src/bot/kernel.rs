@@ -1,8 +1,15 @@
-use std::{sync::Arc, thread::JoinHandle, time::Instant};
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::JoinHandle,
+    time::Instant,
+};
 
 use events::{
     bus::{EventBus, EventFilter},
-    events::{BotDecision, BotEvent, Event, SystemEvent},
+    events::{BotDecision, BotEvent, Event, GameEvent, SystemEvent},
     queue::EventPriority,
 };
 use state::grid::GridDelta;
@@ -21,6 +28,7 @@ pub struct Bot {
     events: Arc<EventBus>,
     ai: Box<dyn DecisionMaker<GridDelta, BotDecision>>,
     state: BotState,
+    shutdown: Arc<AtomicBool>,
     #[allow(dead_code)]
     goal_manager: Arc<GoalManager>,
     #[allow(dead_code)]
@@ -62,12 +70,21 @@ impl Bot {
             events,
             ai,
             state: BotState::default(),
+            shutdown: Arc::new(AtomicBool::new(false)),
             goal_manager,
             pathfinder,
             influence_map,
         }
     }
 
+    /// Flag that stops the run loop once set.
+    ///
+    /// The loop checks the flag after every received event, so the bot exits
+    /// on the next broadcast following the request.
+    pub fn shutdown_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.shutdown)
+    }
+
     /// Spawn the bot on a new thread returning a [`BotHandle`] for control.
     pub fn spawn(self) -> BotHandle {
         let events = Arc::clone(&self.events);
@@ -75,50 +92,35 @@ impl Bot {
         BotHandle { handle, events }
     }
 
-    /// Run the bot loop processing `GridDelta` events and emitting commands.
+    /// Run the bot loop in lock-step with the engine.
     ///
-    /// The loop terminates when the event bus is dropped. The final [`BotState`] is returned.
+    /// `GridDelta` events update the AI's view of the world, while a
+    /// [`GameEvent::TickRequested`] makes the bot decide and answer with a
+    /// decision tagged with the requested tick. The loop terminates on
+    /// [`SystemEvent::EngineStopped`] or once the shutdown flag is set. The
+    /// final [`BotState`] is returned.
     pub fn run(mut self) -> BotState {
-        let filter = EventFilter::new(|e| matches!(e, Event::Grid(_) | Event::System(_)));
+        let filter = EventFilter::new(|e| {
+            matches!(
+                e,
+                Event::Grid(_) | Event::System(_) | Event::Game(GameEvent::TickRequested { .. })
+            )
+        });
         let (_id, rx) = self.events.subscribe_with_filter(Some(filter));
+        // Stopped before subscribing: the stop event was missed.
+        if self.shutdown.load(Ordering::Relaxed) {
+            return self.state;
+        }
         while let Ok(event) = rx.recv() {
+            if self.shutdown.load(Ordering::Relaxed) {
+                break;
+            }
             match event {
                 Event::Grid(delta) => {
-                    let start = Instant::now();
-                    let decision = self.ai.decide(delta);
-                    let duration = start.elapsed();
-                    self.state.record_decision(duration);
-                    if duration > self.config.decision_timeout {
-                        // In future, log or handle long decision times.
-                    }
-                    
-                    // Debug output
-                    match &decision {
-                        BotDecision::Move(dir) => {
-                            println!("Bot {} decided to move {:?}", self.config.id, dir);
-                        }
-                        BotDecision::PlaceBomb => {
-                            println!("Bot {} decided to place bomb", self.config.id);
-                        }
-                        BotDecision::Wait => {
-                            // Only print if we're in debug mode or have a way to enable verbose output
-                        }
-                    }
-                    
-                    // Emit status if available
-                    if let Some(status) = self.ai.status() {
-                        self.events.emit(
-                            Event::Bot(BotEvent::Status { bot_id: self.config.id, status }),
-                            EventPriority::Low,
-                        );
-                    }
-                    self.events.emit(
-                        Event::Bot(BotEvent::Decision {
-                            bot_id: self.config.id,
-                            decision,
-                        }),
-                        EventPriority::Normal,
-                    );
+                    self.ai.observe(delta);
+                }
+                Event::Game(GameEvent::TickRequested { tick }) => {
+                    self.answer_tick(tick);
                 }
                 Event::System(SystemEvent::EngineStopped) => {
                     break;
@@ -131,7 +133,53 @@ impl Bot {
         self.state
     }
 
+    /// Decide on the current view of the world and emit the decision for `tick`.
+    fn answer_tick(&mut self, tick: u64) {
+        let start = Instant::now();
+        let decision = self.ai.decide(GridDelta::None);
+        let duration = start.elapsed();
+        self.state.record_decision(duration);
+        if duration > self.config.decision_timeout {
+            log::debug!(
+                "bot {} exceeded its decision timeout on tick {} ({:?})",
+                self.config.id,
+                tick,
+                duration
+            );
+        }
+
+        // Debug output
+        match &decision {
+            BotDecision::Move(dir) => {
+                log::debug!("Bot {} decided to move {:?}", self.config.id, dir);
+            }
+            BotDecision::PlaceBomb => {
+                log::debug!("Bot {} decided to place bomb", self.config.id);
+            }
+            BotDecision::Detonate(bomb) => {
+                log::debug!("Bot {} decided to detonate {:?}", self.config.id, bomb);
+            }
+            BotDecision::Wait => {
+                // Only print if we're in debug mode or have a way to enable verbose output
+            }
+        }
 
+        // Emit status if available
+        if let Some(status) = self.ai.status() {
+            self.events.emit(
+                Event::Bot(BotEvent::Status { bot_id: self.config.id, status }),
+                EventPriority::Low,
+            );
+        }
+        self.events.emit(
+            Event::Bot(BotEvent::Decision {
+                bot_id: self.config.id,
+                tick,
+                decision,
+            }),
+            EventPriority::Normal,
+        );
+    }
 }
 
 #[cfg(test)]
@@ -141,7 +189,7 @@ mod tests {
 
 
     #[test]
-    fn bot_emits_decision_on_grid_event() {
+    fn bot_answers_tick_request() {
         let bus = Arc::new(EventBus::new());
         let filter = EventFilter::new(|e| matches!(e, Event::Bot(_)));
         let (_id, rx) = bus.subscribe_with_filter(Some(filter));
@@ -152,17 +200,14 @@ mod tests {
         let handle = bot.spawn();
         std::thread::sleep(std::time::Duration::from_millis(10));
         bus.broadcast(Event::Grid(GridDelta::None));
+        bus.broadcast(Event::Game(GameEvent::TickRequested { tick: 7 }));
         let _state = handle.stop();
         bus.process();
         assert!(matches!(
             rx.try_recv().unwrap(),
-            Event::Bot(BotEvent::Decision { .. })
+            Event::Bot(BotEvent::Decision { tick: 7, .. })
         ));
-
-
-}
-
-
+    }
 
     #[test]
     fn spawn_returns_handle_and_stop_yields_state() {
@@ -174,9 +219,24 @@ mod tests {
         let handle = bot.spawn();
         std::thread::sleep(std::time::Duration::from_millis(10));
         bus.broadcast(Event::Grid(GridDelta::None));
+        bus.broadcast(Event::Game(GameEvent::TickRequested { tick: 1 }));
         let state = handle.stop();
         assert_eq!(state.decisions(), 1);
     }
+
+    #[test]
+    fn grid_updates_alone_do_not_produce_decisions() {
+        let bus = Arc::new(EventBus::new());
+        let bot = Bot::new(
+            BotConfig::new("b", crate::ai::AiType::Heuristic),
+            Arc::clone(&bus),
+        );
+        let handle = bot.spawn();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        bus.broadcast(Event::Grid(GridDelta::None));
+        let state = handle.stop();
+        assert_eq!(state.decisions(), 0);
+    }
 }
 
 
@@ -17,10 +17,8 @@ fn test_bot_makes_movement_decisions() {
     
     // Add a bot agent to the pipeline
     let bot_agent = AgentState {
-        id: 1,
-        position: (5, 5),
-        bombs_left: 1,
         power: 2,
+        ..AgentState::new(1, (5, 5))
     };
     
     let delta = GridDelta::AddAgent(bot_agent.clone());
@@ -50,10 +48,8 @@ fn test_bot_uses_fallback_movement() {
     
     // Add a bot agent to the pipeline
     let bot_agent = AgentState {
-        id: 1,
-        position: (5, 5),
-        bombs_left: 1,
         power: 2,
+        ..AgentState::new(1, (5, 5))
     };
     
     let delta = GridDelta::AddAgent(bot_agent.clone());
@@ -1,5 +1,6 @@
 use bot::action::{Action, ActionExecutor, ActionResult};
 use bot::perception::PerceptionSystem;
+use state::components::AgentState;
 use state::grid::GameGrid;
 
 #[test]
@@ -12,9 +13,10 @@ fn perception_system_records_observations() {
 }
 
 #[test]
-fn action_executor_places_bomb() {
+fn action_executor_accepts_bomb_placement() {
     let action = Action::PlaceBomb { position: (0, 0) };
     let mut grid = GameGrid::new(1, 1);
+    grid.add_agent(AgentState::new(0, (0, 0)));
     let res = action.execute(&mut grid);
     assert_eq!(res, ActionResult::Success);
 }
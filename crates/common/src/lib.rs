@@ -1,6 +1,6 @@
-use serde::{Deserialize, Serialize};
+//! Common types and utilities shared across multiple crates.
 
-/// Common types and utilities shared across multiple crates.
+use serde::{Deserialize, Serialize};
 
 pub mod diagnostics;
 pub mod logging;
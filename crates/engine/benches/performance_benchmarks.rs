@@ -2,10 +2,11 @@ use criterion::{Criterion, criterion_group, criterion_main};
 use engine::{Engine, config::EngineConfig};
 
 fn engine_tick_benchmark(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
     c.bench_function("engine_tick", |b| {
         b.iter(|| {
             let (mut engine, _rx, _events) = Engine::new(EngineConfig::default());
-            engine.tick().unwrap();
+            rt.block_on(engine.tick()).unwrap();
         });
     });
 }
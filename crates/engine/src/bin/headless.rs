@@ -0,0 +1,119 @@
+//! Play a batch of matches without a display and report the results.
+//!
+//! ```text
+//! headless [CONFIG] [--matches N] [--seed S] [--bots NAME:AI,...]
+//!          [--json PATH] [--csv PATH]
+//! ```
+//!
+//! `CONFIG` defaults to `config/default.toml`. `--bots` replaces the lineup
+//! of the config, e.g. `--bots old:heuristic,new:planning`.
+
+use std::process::ExitCode;
+
+use engine::simulation::headless::{self, BatchSummary, HeadlessRunner};
+use engine::{UnifiedBotConfig, UnifiedConfig};
+
+/// Decision timeout of bots given with `--bots`.
+const LINEUP_DECISION_TIMEOUT_MS: u64 = 100;
+
+struct Args {
+    config: String,
+    matches: usize,
+    seed: u64,
+    bots: Option<Vec<UnifiedBotConfig>>,
+    json: Option<String>,
+    csv: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        config: "config/default.toml".to_string(),
+        matches: 1,
+        seed: 0,
+        bots: None,
+        json: None,
+        csv: None,
+    };
+    let mut it = std::env::args().skip(1);
+    while let Some(arg) = it.next() {
+        let mut value = || it.next().ok_or_else(|| format!("{} expects a value", arg));
+        match arg.as_str() {
+            "--matches" => {
+                args.matches = value()?.parse().map_err(|e| format!("--matches: {}", e))?
+            }
+            "--seed" => args.seed = value()?.parse().map_err(|e| format!("--seed: {}", e))?,
+            "--bots" => args.bots = Some(parse_lineup(&value()?)?),
+            "--json" => args.json = Some(value()?),
+            "--csv" => args.csv = Some(value()?),
+            flag if flag.starts_with("--") => return Err(format!("unknown option {}", flag)),
+            path => args.config = path.to_string(),
+        }
+    }
+    Ok(args)
+}
+
+/// Parse `NAME:AI,...` into bot configurations.
+fn parse_lineup(lineup: &str) -> Result<Vec<UnifiedBotConfig>, String> {
+    lineup
+        .split(',')
+        .map(|entry| {
+            let (name, ai_type) = entry
+                .split_once(':')
+                .ok_or_else(|| format!("bot {:?} is not NAME:AI", entry))?;
+            Ok(UnifiedBotConfig {
+                name: name.to_string(),
+                ai_type: ai_type.to_string(),
+                rl_mode: false,
+                rl_model_path: None,
+                decision_timeout_ms: LINEUP_DECISION_TIMEOUT_MS,
+            })
+        })
+        .collect()
+}
+
+async fn run(args: Args) -> Result<BatchSummary, Box<dyn std::error::Error>> {
+    let mut config = UnifiedConfig::from_file(&args.config)?.with_env_overrides()?;
+    if let Some(bots) = args.bots {
+        config.bots = bots;
+        config.validate()?;
+    }
+    let records = HeadlessRunner::new(config)
+        .run(args.matches, args.seed)
+        .await?;
+    if let Some(path) = &args.json {
+        headless::write_json(path, &records)?;
+    }
+    if let Some(path) = &args.csv {
+        headless::write_csv(path, &records)?;
+    }
+    Ok(BatchSummary::from_records(&records))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    env_logger::init();
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::from(2);
+        }
+    };
+    match run(args).await {
+        Ok(summary) => {
+            println!(
+                "{} matches in {} ms, {:.1} ticks on average",
+                summary.matches, summary.elapsed_ms, summary.mean_ticks
+            );
+            for (name, wins) in &summary.wins {
+                println!("  {:<20} {} wins", name, wins);
+            }
+            println!("  draws {}, timeouts {}", summary.draws, summary.timeouts);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("headless run failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
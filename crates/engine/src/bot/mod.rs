@@ -1,15 +1,20 @@
 use std::sync::{
     Arc,
-    atomic::{AtomicUsize, Ordering},
+    atomic::{AtomicBool, AtomicUsize, Ordering},
 };
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+pub mod remote;
 
 use events::bus::EventBus;
-use tokio::task::JoinHandle;
 
 use bot::{Bot as KernelBot, BotConfig, BotState};
 
 use events::events::bot_events::BotId;
 
+use remote::{GridSnapshot, RemoteBot, RemoteConnection};
+
 /// Errors related to bot management.
 #[derive(Debug, thiserror::Error)]
 pub enum BotError {
@@ -19,19 +24,36 @@ pub enum BotError {
     /// Bot with the given id was not found.
     #[error("bot not found")]
     NotFound,
+    /// Every spawn point of the map is already taken.
+    #[error("no free spawn point, the map provides {0}")]
+    NoSpawnPoint(usize),
+    /// A remote bot could not connect or be reached.
+    #[error("remote bot error: {0}")]
+    Remote(String),
 }
 
 /// Handle to a running bot task.
 pub struct BotHandle {
     /// Identifier of the bot.
     pub id: BotId,
-    join: JoinHandle<BotState>,
+    /// Maximum time the engine waits for this bot's decision each tick.
+    pub decision_timeout: Duration,
+    shutdown: Arc<AtomicBool>,
+    thread: JoinHandle<BotState>,
 }
 
 impl BotHandle {
     /// Abort the running bot task.
+    ///
+    /// The bot thread exits on the next event it receives.
     pub fn abort(&self) {
-        self.join.abort();
+        self.shutdown.store(true, Ordering::Relaxed);
+    }
+
+    /// Wait for the bot thread to exit, returning the bot's final state or
+    /// `None` if the thread panicked.
+    pub fn join(self) -> Option<BotState> {
+        self.thread.join().ok()
     }
 }
 
@@ -59,14 +81,47 @@ impl BotManager {
             .map_err(|e| BotError::InvalidConfig(e.to_string()))?;
         let id = self.next_id.fetch_add(1, Ordering::Relaxed);
         config.id = id;
+        let decision_timeout = config.decision_timeout;
         let bot = KernelBot::new(config, bus);
-        let join = self.run_bot_decision_loop(bot);
-        Ok(BotHandle { id, join })
+        let shutdown = bot.shutdown_flag();
+        let thread = self.run_bot_decision_loop(bot);
+        Ok(BotHandle {
+            id,
+            decision_timeout,
+            shutdown,
+            thread,
+        })
+    }
+
+    /// Spawn the bridge for a remote bot that completed its handshake.
+    ///
+    /// The bot is welcomed with `grid` and then takes part in the match like
+    /// a local one.
+    pub fn spawn_remote(
+        &self,
+        connection: RemoteConnection,
+        decision_timeout: Duration,
+        grid: GridSnapshot,
+        bus: Arc<EventBus>,
+    ) -> Result<BotHandle, BotError> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let bot = RemoteBot::welcome(id, decision_timeout, connection, grid, bus)?;
+        let shutdown = bot.shutdown_flag();
+        let thread = std::thread::spawn(move || bot.run());
+        Ok(BotHandle {
+            id,
+            decision_timeout,
+            shutdown,
+            thread,
+        })
     }
 
-    /// Run the decision loop for a bot asynchronously.
+    /// Run the decision loop for a bot on a dedicated thread.
+    ///
+    /// The kernel blocks on its event subscription, so it must not occupy a
+    /// runtime worker while the engine awaits decisions.
     pub fn run_bot_decision_loop(&self, bot: KernelBot) -> JoinHandle<BotState> {
-        tokio::spawn(async move { bot.run() })
+        std::thread::spawn(move || bot.run())
     }
 }
 
@@ -74,6 +129,7 @@ impl BotManager {
 mod tests {
     use super::*;
     use bot::AiType;
+    use events::events::{Event, SystemEvent};
 
     #[tokio::test]
     async fn spawns_bot_and_returns_handle() {
@@ -84,4 +140,16 @@ mod tests {
         assert_eq!(handle.id, 0);
         handle.abort();
     }
+
+    #[test]
+    fn stopped_bots_can_be_joined() {
+        let manager = BotManager::new();
+        let bus = Arc::new(EventBus::new());
+        let cfg = BotConfig::new("b", AiType::Heuristic);
+        let handle = manager.spawn_bot(cfg, Arc::clone(&bus)).expect("spawn");
+        // Stop the bot before its thread is likely to have subscribed.
+        handle.abort();
+        bus.broadcast(Event::System(SystemEvent::EngineStopped));
+        assert!(handle.join().is_some());
+    }
 }
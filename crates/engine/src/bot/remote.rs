@@ -0,0 +1,456 @@
+//! Bots running in a separate process, connected over a local TCP socket.
+//!
+//! The protocol is line-delimited JSON: every message is one object on its
+//! own line, tagged by a `type` field.
+//!
+//! 1. The bot connects and sends `hello` with its name, its own version and
+//!    the [`PROTOCOL_VERSION`] it speaks.
+//! 2. The engine answers `welcome` with the bot's id, its decision timeout and
+//!    the grid as it stands, or `rejected` and closes the connection.
+//! 3. Every tick the engine sends `observation` with the grid changes since
+//!    the previous one and the time left to decide, and the bot answers
+//!    `decision` tagged with the same tick. Late or stale decisions are
+//!    dropped, like those of local bots.
+//! 4. `stopped` ends the match; the engine closes the connection after it.
+//!
+//! A bot connects once per match. In a tournament that allows remote bots,
+//! its first connection registers it under the name it announced, and every
+//! connection waits for the bot's next match before the `welcome` is sent.
+
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use bot::BotState;
+use crossbeam::channel::{unbounded, Receiver, RecvTimeoutError, Sender};
+use events::{
+    bus::{EventBus, EventFilter},
+    events::bot_events::BotId,
+    events::{BotDecision, BotEvent, Event, GameEvent, SystemEvent},
+    EventPriority,
+};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use state::{components::Bomb, grid::GridDelta, AgentState, GameGrid, Tile};
+
+use super::BotError;
+
+/// Version of the protocol spoken by this engine.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// How long a freshly connected bot has to send its `hello`.
+pub const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Longest line, newline included, accepted from a bot.
+pub const MAX_LINE_LENGTH: usize = 64 * 1024;
+
+/// Messages sent by a remote bot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    Hello {
+        name: String,
+        version: String,
+        protocol: u32,
+    },
+    Decision {
+        tick: u64,
+        decision: BotDecision,
+    },
+}
+
+/// Messages sent by the engine to a remote bot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    Welcome {
+        bot_id: BotId,
+        protocol: u32,
+        decision_timeout_ms: u64,
+        grid: GridSnapshot,
+    },
+    Observation {
+        tick: u64,
+        /// Milliseconds left to answer once the observation is sent.
+        deadline_ms: u64,
+        deltas: Vec<GridDelta>,
+    },
+    Rejected {
+        reason: String,
+    },
+    Stopped,
+}
+
+/// Full view of the grid handed to a bot when it joins.
+///
+/// Tiles are listed row by row.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GridSnapshot {
+    pub width: usize,
+    pub height: usize,
+    pub tiles: Vec<Tile>,
+    pub agents: Vec<AgentState>,
+    pub bombs: Vec<Bomb>,
+}
+
+impl From<&GameGrid> for GridSnapshot {
+    fn from(grid: &GameGrid) -> Self {
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            tiles: grid.tiles().to_vec(),
+            agents: grid.agents().to_vec(),
+            bombs: grid.bombs().to_vec(),
+        }
+    }
+}
+
+/// Socket the engine serves remote bots on.
+///
+/// Connections are accepted on a background thread and every handshake runs
+/// on a thread of its own, so a bot that never says `hello` holds up nobody
+/// else. The socket closes when the listener is dropped.
+pub struct RemoteBotListener {
+    addr: SocketAddr,
+    handshakes: Receiver<Result<RemoteConnection, BotError>>,
+    closed: Arc<AtomicBool>,
+}
+
+impl RemoteBotListener {
+    /// Listen on `addr`; use port 0 to let the system pick a free port.
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let (tx, handshakes) = unbounded();
+        let closed = Arc::new(AtomicBool::new(false));
+        let stop = Arc::clone(&closed);
+        thread::Builder::new()
+            .name("remote-bot-listener".into())
+            .spawn(move || accept_loop(listener, tx, stop))?;
+        Ok(Self {
+            addr,
+            handshakes,
+            closed,
+        })
+    }
+
+    /// Address bots should connect to.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        Ok(self.addr)
+    }
+
+    /// Wait for the next bot to finish its handshake.
+    ///
+    /// Bots speaking another protocol version are sent `rejected` and
+    /// reported as [`BotError::Remote`].
+    pub fn accept(&self) -> Result<RemoteConnection, BotError> {
+        self.handshakes
+            .recv()
+            .unwrap_or_else(|_| Err(BotError::Remote("listener closed".into())))
+    }
+
+    /// Like [`RemoteBotListener::accept`], but gives up with `None` once
+    /// `deadline` passes.
+    pub fn accept_until(&self, deadline: Instant) -> Option<Result<RemoteConnection, BotError>> {
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        match self.handshakes.recv_timeout(timeout) {
+            Ok(handshake) => Some(handshake),
+            Err(RecvTimeoutError::Timeout) => None,
+            Err(RecvTimeoutError::Disconnected) => {
+                Some(Err(BotError::Remote("listener closed".into())))
+            }
+        }
+    }
+}
+
+impl Drop for RemoteBotListener {
+    fn drop(&mut self) {
+        self.closed.store(true, Ordering::SeqCst);
+        // Wake the accept loop so it sees the flag.
+        let _ = TcpStream::connect(self.addr);
+    }
+}
+
+fn accept_loop(
+    listener: TcpListener,
+    handshakes: Sender<Result<RemoteConnection, BotError>>,
+    closed: Arc<AtomicBool>,
+) {
+    for stream in listener.incoming() {
+        if closed.load(Ordering::SeqCst) {
+            break;
+        }
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                warn!("could not accept remote bot: {}", e);
+                continue;
+            }
+        };
+        let handshakes = handshakes.clone();
+        let spawned = thread::Builder::new()
+            .name("remote-bot-handshake".into())
+            .spawn(move || {
+                let _ = handshakes.send(handshake(stream));
+            });
+        if let Err(e) = spawned {
+            warn!("could not start remote bot handshake: {}", e);
+        }
+    }
+}
+
+/// Read the bot's `hello` and check its protocol version.
+fn handshake(stream: TcpStream) -> Result<RemoteConnection, BotError> {
+    let peer = stream.peer_addr().map_err(remote_error)?;
+    let mut lines = JsonLines::new(stream).map_err(remote_error)?;
+    let hello = lines
+        .receive(Instant::now() + HANDSHAKE_TIMEOUT)
+        .map_err(remote_error)?;
+    match hello {
+        Some(ClientMessage::Hello {
+            name,
+            version,
+            protocol,
+        }) if protocol == PROTOCOL_VERSION => {
+            debug!("remote bot {} {} connected from {}", name, version, peer);
+            Ok(RemoteConnection {
+                name,
+                version,
+                peer,
+                lines,
+            })
+        }
+        Some(ClientMessage::Hello { protocol, .. }) => {
+            let reason = format!(
+                "unsupported protocol version {}, expected {}",
+                protocol, PROTOCOL_VERSION
+            );
+            let _ = lines.send(&ServerMessage::Rejected {
+                reason: reason.clone(),
+            });
+            Err(BotError::Remote(reason))
+        }
+        Some(_) => {
+            let reason = "expected hello".to_string();
+            let _ = lines.send(&ServerMessage::Rejected {
+                reason: reason.clone(),
+            });
+            Err(BotError::Remote(reason))
+        }
+        None => Err(BotError::Remote(format!("no hello from {}", peer))),
+    }
+}
+
+/// A remote bot that completed the handshake.
+pub struct RemoteConnection {
+    name: String,
+    version: String,
+    peer: SocketAddr,
+    lines: JsonLines,
+}
+
+impl RemoteConnection {
+    /// Name announced by the bot.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Version announced by the bot.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Address the bot connected from.
+    pub fn peer(&self) -> SocketAddr {
+        self.peer
+    }
+}
+
+/// Bridge between the event bus and a remote bot.
+///
+/// It stands where a local kernel would: it follows the same events and
+/// answers tick requests with the decisions read from the socket.
+pub(super) struct RemoteBot {
+    id: BotId,
+    decision_timeout: Duration,
+    connection: RemoteConnection,
+    events: Arc<EventBus>,
+    rx: Receiver<Event>,
+    shutdown: Arc<AtomicBool>,
+    state: BotState,
+}
+
+impl RemoteBot {
+    /// Send the welcome to the bot; the bridge is ready to run afterwards.
+    ///
+    /// The bridge subscribes before returning so that no change made after
+    /// `grid` was taken, such as the bot's own agent appearing, is missed.
+    pub(super) fn welcome(
+        id: BotId,
+        decision_timeout: Duration,
+        mut connection: RemoteConnection,
+        grid: GridSnapshot,
+        events: Arc<EventBus>,
+    ) -> Result<Self, BotError> {
+        let filter = EventFilter::new(|e| {
+            matches!(
+                e,
+                Event::Grid(_) | Event::System(_) | Event::Game(GameEvent::TickRequested { .. })
+            )
+        });
+        let (_id, rx) = events.subscribe_with_filter(Some(filter));
+        connection
+            .lines
+            .send(&ServerMessage::Welcome {
+                bot_id: id,
+                protocol: PROTOCOL_VERSION,
+                decision_timeout_ms: decision_timeout.as_millis() as u64,
+                grid,
+            })
+            .map_err(remote_error)?;
+        Ok(Self {
+            id,
+            decision_timeout,
+            connection,
+            events,
+            rx,
+            shutdown: Arc::new(AtomicBool::new(false)),
+            state: BotState::default(),
+        })
+    }
+
+    pub(super) fn shutdown_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.shutdown)
+    }
+
+    /// Relay events until the engine stops or the bot disconnects.
+    ///
+    /// A bot that disconnects simply stops deciding for the rest of the match.
+    pub(super) fn run(mut self) -> BotState {
+        let mut deltas = Vec::new();
+        while let Ok(event) = self.rx.recv() {
+            if self.shutdown.load(Ordering::Relaxed) {
+                break;
+            }
+            match event {
+                Event::Grid(delta) => deltas.push(delta),
+                Event::Game(GameEvent::TickRequested { tick }) => {
+                    if let Err(e) = self.answer_tick(tick, std::mem::take(&mut deltas)) {
+                        warn!("remote bot {} disconnected: {}", self.id, e);
+                        return self.state;
+                    }
+                }
+                Event::System(SystemEvent::EngineStopped) => break,
+                _ => {}
+            }
+        }
+        let _ = self.connection.lines.send(&ServerMessage::Stopped);
+        self.state
+    }
+
+    /// Send the observation for `tick` and relay the bot's decision if it
+    /// arrives in time.
+    fn answer_tick(&mut self, tick: u64, deltas: Vec<GridDelta>) -> io::Result<()> {
+        let start = Instant::now();
+        self.connection.lines.send(&ServerMessage::Observation {
+            tick,
+            deadline_ms: self.decision_timeout.as_millis() as u64,
+            deltas,
+        })?;
+        let deadline = start + self.decision_timeout;
+        loop {
+            match self.connection.lines.receive(deadline)? {
+                Some(ClientMessage::Decision { tick: t, decision }) if t == tick => {
+                    self.state.record_decision(start.elapsed());
+                    self.events.emit(
+                        Event::Bot(BotEvent::Decision {
+                            bot_id: self.id,
+                            tick,
+                            decision,
+                        }),
+                        EventPriority::Normal,
+                    );
+                    return Ok(());
+                }
+                Some(message) => {
+                    debug!("remote bot {} sent stale {:?}", self.id, message);
+                }
+                None => {
+                    debug!("remote bot {} missed tick {}", self.id, tick);
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+/// JSON-lines framing over a TCP stream.
+struct JsonLines {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    /// Bytes of a line whose end has not arrived yet.
+    pending: Vec<u8>,
+}
+
+impl JsonLines {
+    fn new(stream: TcpStream) -> io::Result<Self> {
+        stream.set_nodelay(true)?;
+        Ok(Self {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+            pending: Vec::new(),
+        })
+    }
+
+    fn send(&mut self, message: &ServerMessage) -> io::Result<()> {
+        let mut line = serde_json::to_vec(message)?;
+        line.push(b'\n');
+        self.writer.write_all(&line)
+    }
+
+    /// Read the next message, or `None` once `deadline` passes.
+    ///
+    /// Lines that are not valid messages are logged and skipped. A line
+    /// longer than [`MAX_LINE_LENGTH`] is an error.
+    fn receive(&mut self, deadline: Instant) -> io::Result<Option<ClientMessage>> {
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            let room = MAX_LINE_LENGTH.saturating_sub(self.pending.len());
+            if room == 0 {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("line longer than {} bytes", MAX_LINE_LENGTH),
+                ));
+            }
+            self.reader.get_ref().set_read_timeout(Some(remaining))?;
+            let mut limited = (&mut self.reader).take(room as u64);
+            match limited.read_until(b'\n', &mut self.pending) {
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(_) if self.pending.ends_with(b"\n") => {
+                    let line = std::mem::take(&mut self.pending);
+                    match serde_json::from_slice(&line) {
+                        Ok(message) => return Ok(Some(message)),
+                        Err(e) => warn!("ignoring malformed remote bot message: {}", e),
+                    }
+                }
+                Ok(_) if self.pending.len() >= MAX_LINE_LENGTH => {}
+                Ok(_) => return Err(ErrorKind::UnexpectedEof.into()),
+                Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+fn remote_error(e: io::Error) -> BotError {
+    BotError::Remote(e.to_string())
+}
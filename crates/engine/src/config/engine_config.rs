@@ -1,8 +1,12 @@
-use std::{fs, path::Path};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 use serde::{Deserialize, Serialize};
+use state::{ClassicGenerator, GameGrid, MapGenerator, RandomGenerator};
 
-use super::{ConfigError, GameRules};
+use super::{ConfigError, GameRules, MapFile};
 
 /// Configuration for the game engine.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -15,6 +19,31 @@ pub struct EngineConfig {
     pub tick_rate: u32,
     /// Game rules applied to the simulation.
     pub rules: GameRules,
+    /// Seed for every random choice made during a match.
+    #[serde(default)]
+    pub seed: u64,
+    /// Generator producing the map layout.
+    #[serde(default)]
+    pub map: MapConfig,
+    /// Shuffle the order in which spawn points are handed out using `seed`.
+    #[serde(default)]
+    pub shuffle_spawns: bool,
+}
+
+/// Selects how the map is built.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MapConfig {
+    /// The fixed classic layout, see [`ClassicGenerator`].
+    #[default]
+    Classic,
+    /// A seeded random layout, see [`RandomGenerator`].
+    Random(RandomGenerator),
+    /// A hand-authored arena, see [`MapFile`].
+    File {
+        /// Path of the map file.
+        path: PathBuf,
+    },
 }
 
 impl Default for EngineConfig {
@@ -24,6 +53,9 @@ impl Default for EngineConfig {
             height: 11,
             tick_rate: 60,
             rules: GameRules::default(),
+            seed: 0,
+            map: MapConfig::default(),
+            shuffle_spawns: false,
         }
     }
 }
@@ -47,8 +79,65 @@ impl EngineConfig {
                 "grid dimensions must be greater than zero".into(),
             ));
         }
+        if !(1..=u8::MAX as u32).contains(&self.rules.bomb_timer) {
+            return Err(ConfigError::Invalid(
+                "bomb_timer must lie in 1..=255 ticks".into(),
+            ));
+        }
+        if self.rules.starting_lives == 0 {
+            return Err(ConfigError::Invalid(
+                "starting_lives must be at least one".into(),
+            ));
+        }
+        if let Some(sudden_death) = &self.rules.sudden_death {
+            if sudden_death.collapse_interval == 0 {
+                return Err(ConfigError::Invalid(
+                    "sudden_death.collapse_interval must be at least one tick".into(),
+                ));
+            }
+        }
+        let powerups = &self.rules.powerups;
+        let rates = state::PowerUpType::ALL.map(|kind| powerups.drop_rate(kind));
+        if rates.iter().any(|r| !(0.0..=1.0).contains(r)) || rates.iter().sum::<f32>() > 1.0 {
+            return Err(ConfigError::Invalid(
+                "power-up drop rates must lie in 0.0..=1.0 and sum to at most 1.0".into(),
+            ));
+        }
+        let grid = self.build_grid()?;
+        let spawns = grid.spawn_points().len();
+        if spawns < self.rules.max_players as usize {
+            return Err(ConfigError::Invalid(format!(
+                "map provides {} spawn points but up to {} players are allowed",
+                spawns, self.rules.max_players
+            )));
+        }
         Ok(())
     }
+
+    /// Build the grid described by `map`, using the match seed for generated maps.
+    pub fn build_grid(&self) -> Result<GameGrid, ConfigError> {
+        let layout = match &self.map {
+            MapConfig::Classic => ClassicGenerator.layout(self.width, self.height),
+            MapConfig::Random(generator) => {
+                generator.generate(self.width, self.height, self.seed)?
+            }
+            MapConfig::File { path } => {
+                let layout = MapFile::from_path(path)?.parse()?;
+                if (layout.width, layout.height) != (self.width, self.height) {
+                    return Err(ConfigError::Invalid(format!(
+                        "map {} is {}x{} but the engine is configured for {}x{}",
+                        path.display(),
+                        layout.width,
+                        layout.height,
+                        self.width,
+                        self.height
+                    )));
+                }
+                layout
+            }
+        };
+        Ok(GameGrid::from_layout(layout))
+    }
 }
 
 #[cfg(test)]
@@ -72,5 +161,73 @@ mod tests {
         assert_eq!(cfg.width, 5);
         assert_eq!(cfg.rules.bomb_timer, 5);
         assert_eq!(cfg.tick_rate, 30);
+        assert_eq!(cfg.map, MapConfig::Classic);
+    }
+
+    #[test]
+    fn random_map_is_selected_by_config() {
+        let json = r#"{
+            "width": 15,
+            "height": 13,
+            "tick_rate": 30,
+            "rules": {"max_players": 4, "bomb_timer": 3, "starting_lives": 1},
+            "seed": 11,
+            "map": {"kind": "random", "crate_density": 0.4, "symmetry": "two_way"}
+        }"#;
+        let cfg: EngineConfig = serde_json::from_str(json).unwrap();
+        let grid = cfg.build_grid().unwrap();
+        let again = cfg.build_grid().unwrap();
+        assert_eq!(grid.tiles(), again.tiles());
+        assert_eq!(grid.spawn_points().len(), 8);
+
+        let square_only = EngineConfig {
+            map: MapConfig::Random(state::RandomGenerator {
+                crate_density: 0.4,
+                symmetry: state::Symmetry::EightWay,
+            }),
+            ..cfg
+        };
+        assert!(matches!(square_only.validate(), Err(ConfigError::Map(_))));
+    }
+
+    #[test]
+    fn map_file_is_loaded_and_validated() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("arena.toml");
+        fs::write(
+            &path,
+            "name = \"Arena\"\nlayout = \"\"\"\n#######\n#1.+.2#\n#.#r#.#\n#######\n\"\"\"\n",
+        )
+        .unwrap();
+        let mut cfg = EngineConfig {
+            width: 7,
+            height: 4,
+            map: MapConfig::File { path: path.clone() },
+            ..EngineConfig::default()
+        };
+        cfg.rules.max_players = 2;
+        cfg.validate().unwrap();
+        let grid = cfg.build_grid().unwrap();
+        assert_eq!(grid.spawn_points(), &[(1, 1), (5, 1)]);
+        assert_eq!(
+            grid.tile(3, 2),
+            Some(state::Tile::PowerUp(state::PowerUpType::Remote))
+        );
+
+        cfg.rules.max_players = 4;
+        let err = cfg.validate().unwrap_err().to_string();
+        assert!(err.contains("2 spawn points"), "{err}");
+
+        cfg.width = 9;
+        let err = cfg.validate().unwrap_err().to_string();
+        assert!(err.contains("7x4"), "{err}");
+
+        fs::write(&path, "layout = \"#1#2#\"\n").unwrap();
+        cfg.width = 5;
+        cfg.height = 1;
+        assert!(matches!(
+            cfg.validate(),
+            Err(ConfigError::Map(state::MapError::Disconnected))
+        ));
     }
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use state::PowerUpType;
 
 /// Rules governing gameplay.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -9,6 +10,24 @@ pub struct GameRules {
     pub bomb_timer: u32,
     /// Starting lives for each player.
     pub starting_lives: u8,
+    /// Ticks a hit agent stays off the grid before it respawns.
+    #[serde(default = "default_respawn_delay")]
+    pub respawn_delay: u32,
+    /// Ticks a respawned agent cannot be hit.
+    #[serde(default = "default_invulnerability_ticks")]
+    pub invulnerability_ticks: u32,
+    /// Rules for settling simultaneous moves.
+    #[serde(default)]
+    pub movement: MovementRules,
+    /// Power-up drop rates and stat limits.
+    #[serde(default)]
+    pub powerups: PowerUpRules,
+    /// Limits that end a match before a single agent is left.
+    #[serde(default)]
+    pub limits: MatchLimits,
+    /// Shrinking arena closing in on long matches; `None` disables it.
+    #[serde(default)]
+    pub sudden_death: Option<SuddenDeathRules>,
 }
 
 impl Default for GameRules {
@@ -17,6 +36,172 @@ impl Default for GameRules {
             max_players: 4,
             bomb_timer: 3,
             starting_lives: 3,
+            respawn_delay: default_respawn_delay(),
+            invulnerability_ticks: default_invulnerability_ticks(),
+            movement: MovementRules::default(),
+            powerups: PowerUpRules::default(),
+            limits: MatchLimits::default(),
+            sudden_death: None,
         }
     }
 }
+
+fn default_respawn_delay() -> u32 {
+    30
+}
+
+fn default_invulnerability_ticks() -> u32 {
+    60
+}
+
+/// Rules applied when several agents move during the same tick.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MovementRules {
+    /// How agents stepping onto the same tile are settled.
+    pub conflict: MoveConflictRule,
+    /// Whether two adjacent agents may trade places in a single tick.
+    pub allow_swaps: bool,
+}
+
+impl Default for MovementRules {
+    fn default() -> Self {
+        Self {
+            conflict: MoveConflictRule::BothBlocked,
+            allow_swaps: false,
+        }
+    }
+}
+
+/// Resolution for agents contesting the same empty tile.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MoveConflictRule {
+    /// Nobody enters the contested tile; every contestant stays put.
+    BothBlocked,
+    /// Every contestant is pushed back one tile opposite to its move, if that tile is free.
+    BothBounce,
+    /// The contestant with the lowest id enters the tile.
+    PriorityById,
+    /// A contestant drawn from the match seed and tick number enters the tile.
+    PriorityBySeed,
+}
+
+/// Limits ending a match that has not been decided by eliminations.
+///
+/// A match that hits a limit ends as a timeout, ranking the agents still in
+/// the game by the lives they have left.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct MatchLimits {
+    /// Ticks after which the match ends.
+    pub max_ticks: Option<u64>,
+    /// Wall-clock seconds after which the match ends, counted from its first tick.
+    pub max_seconds: Option<u64>,
+}
+
+impl MatchLimits {
+    /// Tick limit used where a match has to finish but no limit is set.
+    pub const FALLBACK_MAX_TICKS: u64 = 3000;
+
+    /// These limits, capped at [`Self::FALLBACK_MAX_TICKS`] when neither
+    /// `max_ticks` nor `max_seconds` is set.
+    pub fn bounded(&self) -> Self {
+        let mut limits = self.clone();
+        if limits.max_ticks.is_none() && limits.max_seconds.is_none() {
+            limits.max_ticks = Some(Self::FALLBACK_MAX_TICKS);
+        }
+        limits
+    }
+}
+
+/// Timing of the sudden-death arena collapse.
+///
+/// From `start_tick` on, one tile every `collapse_interval` ticks turns into
+/// a wall, spiralling clockwise from the top-left corner towards the centre.
+/// Tiles that already are walls are skipped. Each collapse is announced
+/// `warning_ticks` ahead of time.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SuddenDeathRules {
+    /// Tick on which the first tile collapses.
+    pub start_tick: u64,
+    /// Ticks between two collapsing tiles.
+    pub collapse_interval: u32,
+    /// Ticks between the announcement of a collapse and the collapse itself.
+    pub warning_ticks: u32,
+}
+
+impl Default for SuddenDeathRules {
+    fn default() -> Self {
+        Self {
+            start_tick: 1200,
+            collapse_interval: 2,
+            warning_ticks: 10,
+        }
+    }
+}
+
+/// Power-up drop rates and the limits of the stats they raise.
+///
+/// Each rate is the probability that a destroyed soft crate drops that kind
+/// of power-up; the rates together must not exceed `1.0`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct PowerUpRules {
+    /// Drop rate of [`PowerUpType::BombCount`].
+    pub bomb_count: f32,
+    /// Drop rate of [`PowerUpType::BlastRadius`].
+    pub blast_radius: f32,
+    /// Drop rate of [`PowerUpType::Speed`].
+    pub speed: f32,
+    /// Drop rate of [`PowerUpType::Kick`].
+    pub kick: f32,
+    /// Drop rate of [`PowerUpType::Remote`].
+    pub remote: f32,
+    /// Drop rate of [`PowerUpType::Pierce`].
+    pub pierce: f32,
+    /// Most bombs an agent can hold.
+    pub max_bombs: u8,
+    /// Largest blast radius an agent can reach.
+    pub max_power: u8,
+    /// Most tiles an agent can move per tick.
+    pub max_speed: u8,
+}
+
+impl Default for PowerUpRules {
+    fn default() -> Self {
+        Self {
+            bomb_count: 0.1,
+            blast_radius: 0.1,
+            speed: 0.05,
+            kick: 0.03,
+            remote: 0.02,
+            pierce: 0.02,
+            max_bombs: 8,
+            max_power: 8,
+            max_speed: 3,
+        }
+    }
+}
+
+impl PowerUpRules {
+    /// Drop rate configured for `kind`.
+    pub fn drop_rate(&self, kind: PowerUpType) -> f32 {
+        match kind {
+            PowerUpType::BombCount => self.bomb_count,
+            PowerUpType::BlastRadius => self.blast_radius,
+            PowerUpType::Speed => self.speed,
+            PowerUpType::Kick => self.kick,
+            PowerUpType::Remote => self.remote,
+            PowerUpType::Pierce => self.pierce,
+        }
+    }
+
+    /// Pick the power-up dropped by a crate given a uniform `roll` in `0.0..1.0`.
+    pub fn drop_for_roll(&self, roll: f32) -> Option<PowerUpType> {
+        let mut threshold = 0.0;
+        PowerUpType::ALL.into_iter().find(|&kind| {
+            threshold += self.drop_rate(kind);
+            roll < threshold
+        })
+    }
+}
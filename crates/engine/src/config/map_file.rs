@@ -0,0 +1,60 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+use state::MapLayout;
+
+use super::ConfigError;
+
+/// Hand-authored arena stored as TOML.
+///
+/// ```toml
+/// name = "Crossroads"
+/// layout = """
+/// #######
+/// #1.+.2#
+/// #.#k#.#
+/// #3.+.4#
+/// #######
+/// """
+/// ```
+///
+/// The layout symbols are described in [`state::grid::ascii`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MapFile {
+    /// Display name of the arena.
+    #[serde(default)]
+    pub name: String,
+    /// Rows of the arena, one line per row.
+    pub layout: String,
+}
+
+impl MapFile {
+    /// Load a map file from disk.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path).map_err(|e| {
+            ConfigError::Invalid(format!("cannot read map file {}: {}", path.display(), e))
+        })?;
+        Ok(toml::from_str(&data)?)
+    }
+
+    /// Parse the layout, checking its shape, spawns and reachability.
+    pub fn parse(&self) -> Result<MapLayout, ConfigError> {
+        Ok(MapLayout::from_ascii(&self.layout)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_maps_parse() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../config/maps");
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let layout = MapFile::from_path(&path).unwrap().parse().unwrap();
+            assert!(layout.spawns.len() >= 2, "{}", path.display());
+        }
+    }
+}
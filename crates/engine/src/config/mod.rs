@@ -1,11 +1,15 @@
 pub mod engine_config;
 pub mod game_rules;
+pub mod map_file;
 pub mod tournament_config;
 pub mod unified_config;
 
-pub use engine_config::EngineConfig;
-pub use game_rules::GameRules;
-pub use tournament_config::{ScoringSystem, TournamentConfig, TournamentFormat};
+pub use engine_config::{EngineConfig, MapConfig};
+pub use game_rules::{
+    GameRules, MatchLimits, MoveConflictRule, MovementRules, PowerUpRules, SuddenDeathRules,
+};
+pub use map_file::MapFile;
+pub use tournament_config::{ScoringSystem, Seeding, TournamentConfig, TournamentFormat};
 pub use unified_config::{
     AIConfig, BombConfig, BotConfig as UnifiedBotConfig, ConfigError, EventBusConfig,
     LoggingConfig, RLConfig, UnifiedConfig,
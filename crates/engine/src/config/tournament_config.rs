@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TournamentConfig {
@@ -9,31 +10,115 @@ pub struct TournamentConfig {
     pub game_timeout_seconds: u64,
     pub scoring_system: ScoringSystem,
     pub registration_timeout_seconds: u64,
+    /// Serve the remote bot protocol so bots running as separate programs
+    /// can register and play.
     pub allow_remote_bots: bool,
+    /// Address remote bots connect to when `allow_remote_bots` is set.
+    #[serde(default = "default_remote_listen_addr")]
+    pub remote_listen_addr: String,
+    /// Decision timeout given to every remote bot.
+    #[serde(default = "default_remote_decision_timeout_ms")]
+    pub remote_decision_timeout_ms: u64,
     pub persist_results: bool,
+    /// Where archives are written when `persist_results` is set.
+    #[serde(default = "default_results_dir")]
+    pub results_dir: PathBuf,
+    /// Elo ratings carried from one tournament to the next, keyed by bot
+    /// name; read when the tournament starts and written when it ends.
+    #[serde(default)]
+    pub ratings_file: Option<PathBuf>,
+    /// Progress saved after every match, for [`TournamentManager::resume`].
+    ///
+    /// [`TournamentManager::resume`]: crate::TournamentManager::resume
+    #[serde(default)]
+    pub checkpoint_file: Option<PathBuf>,
+}
+
+fn default_results_dir() -> PathBuf {
+    PathBuf::from("results")
+}
+
+fn default_remote_listen_addr() -> String {
+    "127.0.0.1:7878".into()
+}
+
+fn default_remote_decision_timeout_ms() -> u64 {
+    100
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TournamentFormat {
     RoundRobin { total_rounds: u32 },
-    SingleElimination { bracket_size: u32 },
+    SingleElimination {
+        bracket_size: u32,
+        #[serde(default)]
+        seeding: Seeding,
+    },
     Swiss { rounds: u32 },
+    /// Every round splits the field into free-for-all matches of up to
+    /// `players_per_match` bots, rotating opponents and spawn positions.
+    FreeForAll {
+        rounds: u32,
+        players_per_match: u32,
+    },
+}
+
+/// How players are placed in an elimination bracket, best seed first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum Seeding {
+    /// In the order the bots registered.
+    #[default]
+    RegistrationOrder,
+    /// Shuffled with the given seed.
+    Random { seed: u64 },
+    /// By prior rating, highest first; unrated bots follow in registration order.
+    Rating,
 }
 
+/// How a bot's games add up to its tournament points.
+///
+/// Ties on points go to the bot with more game points (1 per win or bye, a
+/// half per draw), then the higher Buchholz score, then the higher
+/// Sonneborn-Berger score, then the earlier registration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ScoringSystem {
+    /// Points per win or bye and per loss; a draw scores the average of both.
     WinLoss {
         win_points: u32,
         loss_points: u32,
     },
+    /// Seconds survived across all games, times `time_multiplier`.
     Survival {
         time_multiplier: f32,
     },
+    /// `crate_points` per crate destroyed and `enemy_points` per opponent
+    /// outlasted, i.e. finishing below the bot in a game.
     Destruction {
         crate_points: u32,
         enemy_points: u32,
     },
+    /// Weighted sum of the statistics `wins`, `draws`, `losses`, `byes`,
+    /// `survival` (seconds), `crates`, `powerups` and `outlasted`; other keys
+    /// are rejected when the configuration is validated.
     Hybrid {
         weights: HashMap<String, f32>,
     },
+    /// `points[i]` for finishing in place `i + 1`; places past the table score nothing.
+    Placement {
+        points: Vec<u32>,
+    },
+}
+
+impl ScoringSystem {
+    /// Statistics a [`ScoringSystem::Hybrid`] can weigh.
+    pub const HYBRID_STATS: [&'static str; 8] = [
+        "wins",
+        "draws",
+        "losses",
+        "byes",
+        "survival",
+        "crates",
+        "powerups",
+        "outlasted",
+    ];
 }
@@ -3,7 +3,10 @@ use std::{fs, path::Path};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use super::{engine_config::EngineConfig, tournament_config::TournamentConfig};
+use super::{
+    engine_config::EngineConfig,
+    tournament_config::{ScoringSystem, TournamentConfig},
+};
 
 #[derive(Debug, Error)]
 pub enum ConfigError {
@@ -15,6 +18,8 @@ pub enum ConfigError {
     Json(#[from] serde_json::Error),
     #[error("invalid configuration: {0}")]
     Invalid(String),
+    #[error("map generation failed: {0}")]
+    Map(#[from] state::MapError),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +38,16 @@ pub struct BotConfig {
 }
 
 impl BotConfig {
+    /// `ai_type` of bots that play from a separate program over the remote
+    /// bot protocol.
+    pub const REMOTE_AI_TYPE: &'static str = "Remote";
+
+    /// Whether the bot connects over the remote bot protocol instead of
+    /// running inside the engine.
+    pub fn is_remote(&self) -> bool {
+        self.ai_type.eq_ignore_ascii_case(Self::REMOTE_AI_TYPE)
+    }
+
     pub fn validate(&self) -> Result<(), ConfigError> {
         if self.name.trim().is_empty() {
             return Err(ConfigError::Invalid("bot name cannot be empty".into()));
@@ -44,6 +59,21 @@ impl BotConfig {
         }
         Ok(())
     }
+
+    /// Settings the bot runtime is spawned with.
+    pub fn runtime_config(&self) -> ::bot::BotConfig {
+        use ::bot::AiType;
+        let ai_type = match self.ai_type.to_lowercase().as_str() {
+            "reactive" => AiType::Reactive,
+            "planning" => AiType::Planning,
+            _ => AiType::Heuristic,
+        };
+        let mut config = ::bot::BotConfig::new(&self.name, ai_type);
+        config.rl_mode = self.rl_mode;
+        config.rl_model_path = self.rl_model_path.clone();
+        config.decision_timeout = std::time::Duration::from_millis(self.decision_timeout_ms);
+        config
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -104,6 +134,19 @@ impl UnifiedConfig {
                 "number of bots exceeds engine rules".into(),
             ));
         }
+        if let Some(ScoringSystem::Hybrid { weights }) =
+            self.tournament.as_ref().map(|t| &t.scoring_system)
+        {
+            if let Some(stat) = weights
+                .keys()
+                .find(|stat| !ScoringSystem::HYBRID_STATS.contains(&stat.as_str()))
+            {
+                return Err(ConfigError::Invalid(format!(
+                    "unknown hybrid scoring statistic: {}",
+                    stat
+                )));
+            }
+        }
         Ok(())
     }
 
@@ -171,4 +214,26 @@ mod tests {
         };
         assert!(cfg.validate().is_err());
     }
+
+    #[test]
+    fn validate_rejects_unknown_hybrid_weights() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "{}", r#"{
+            "engine":{"width":5,"height":5,"tick_rate":60,"rules":{"max_players":4,"bomb_timer":3,"starting_lives":3}},
+            "event_bus":{"buffer_size":10,"max_subscribers":10},
+            "bots":[],
+            "tournament":{
+                "name":"t","format":{"RoundRobin":{"total_rounds":1}},"max_concurrent_games":1,
+                "game_timeout_seconds":10,"registration_timeout_seconds":1,"allow_remote_bots":false,
+                "persist_results":false,"scoring_system":{"Hybrid":{"weights":{"wins":1.0,"win":2.0}}}
+            },
+            "ai":{},
+            "rl":null,
+            "bombs":{},
+            "logging":{"level":"info"}
+        }"#).unwrap();
+        let path = file.into_temp_path();
+        let err = UnifiedConfig::from_file(path.to_str().unwrap()).unwrap_err();
+        assert!(err.to_string().ends_with("statistic: win"), "{}", err);
+    }
 }
@@ -159,7 +159,7 @@ impl GameDisplay {
                     .queue(Print("▓▓"))?
                     .queue(ResetColor)?;
             }
-            Tile::PowerUp => {
+            Tile::PowerUp(_) => {
                 stdout
                     .queue(SetBackgroundColor(Color::Magenta))?
                     .queue(SetForegroundColor(Color::White))?
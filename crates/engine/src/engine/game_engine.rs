@@ -1,24 +1,33 @@
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex, RwLock};
-use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
+use super::move_resolution::{is_tile_walkable, resolve_moves, step};
+use super::outcome::MatchOutcome;
 use super::scheduler::TaskScheduler;
+use super::sudden_death::CollapseSchedule;
 use crate::{
-    bot::{BotError, BotHandle, BotManager},
+    bot::{
+        remote::{GridSnapshot, RemoteConnection},
+        BotError, BotHandle, BotManager,
+    },
     config::EngineConfig,
     simulation::{DeterminismChecker, Replay, ReplayRecorder},
-    systems::System,
+    systems::{apply_powerup, System},
 };
 use ::bot::BotConfig;
+use bombs::BombManager;
+use common::Direction;
 
 use crossbeam::channel::Receiver;
 use events::{
     bus::{EventBus, EventFilter},
     events::bot_events::BotId,
-    events::{BotDecision, BotEvent, Event, GameEvent},
-    queue::EventPriority,
+    events::{BombEvent, BotDecision, BotEvent, Event, GameEvent, SystemEvent},
 };
-use log::error;
-use state::{GameGrid, components::Bomb, grid::GridDelta};
+use log::{debug, warn};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use state::{components::Bomb, grid::GridDelta, AgentState, BombId, GameGrid, Tile};
 use thiserror::Error;
 use tokio::sync::watch;
 
@@ -34,6 +43,18 @@ pub enum EngineError {
     BotCommandProcessing(String),
 }
 
+/// Interval between checks for outstanding bot decisions.
+const DECISION_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Spawn points of `grid` in the order they are handed to bots.
+fn spawn_order(config: &EngineConfig, grid: &GameGrid) -> Vec<(u16, u16)> {
+    let mut spawns = grid.spawn_points().to_vec();
+    if config.shuffle_spawns {
+        spawns.shuffle(&mut StdRng::seed_from_u64(config.seed));
+    }
+    spawns
+}
+
 /// Core game engine advancing the simulation and broadcasting changes.
 pub struct Engine {
     config: EngineConfig,
@@ -47,19 +68,44 @@ pub struct Engine {
     replay_recorder: ReplayRecorder,
     determinism_checker: DeterminismChecker,
     bot_command_rx: Receiver<Event>,
+    /// Grid changes broadcast on the bus, drained into the replay recorder.
+    grid_rx: Receiver<Event>,
     tick: u64,
     bot_status: HashMap<BotId, String>,
-    movement_cooldowns: HashMap<BotId, std::time::Instant>, // Track movement cooldowns
+    spawn_order: Vec<(u16, u16)>,
+    /// Spawn point each bot's agent first entered the grid on.
+    homes: HashMap<BotId, (u16, u16)>,
+    /// Hit agents waiting off the grid, with the tick they may return on.
+    respawns: BTreeMap<BotId, (u64, AgentState)>,
+    /// Bombs placed through the engine, tracking which are armed for remote detonation.
+    bomb_manager: BombManager,
+    /// Sudden-death collapse order, when the rules enable it.
+    collapse: Option<CollapseSchedule>,
+    /// Eliminated agents with the tick they went out on, in elimination order.
+    eliminated: Vec<(BotId, u64)>,
+    /// When the first tick ran, for the wall-clock match limit.
+    started: Option<Instant>,
 }
 
 impl Engine {
     /// Creates a new engine configured via [`EngineConfig`].
     pub fn new(config: EngineConfig) -> (Self, watch::Receiver<GridDelta>, Arc<EventBus>) {
-        let grid = GameGrid::new(config.width, config.height);
+        let grid = config.build_grid().unwrap_or_else(|e| {
+            warn!("map generation failed ({}), using the classic layout", e);
+            GameGrid::new(config.width, config.height)
+        });
+        let spawn_order = spawn_order(&config, &grid);
+        let collapse = config
+            .rules
+            .sudden_death
+            .as_ref()
+            .map(|rules| CollapseSchedule::new(&grid, rules));
         let (tx, rx) = watch::channel(GridDelta::None);
         let events = Arc::new(EventBus::new());
         let filter = EventFilter::new(|e| matches!(e, Event::Bot(_)));
         let (_id, cmd_rx) = events.subscribe_with_filter(Some(filter));
+        let filter = EventFilter::new(|e| matches!(e, Event::Grid(_)));
+        let (_id, grid_rx) = events.subscribe_with_filter(Some(filter));
         let bot_manager = BotManager::new();
         (
             Self {
@@ -74,9 +120,16 @@ impl Engine {
                 bot_manager,
                 bots: Vec::new(),
                 bot_command_rx: cmd_rx,
+                grid_rx,
                 tick: 0,
                 bot_status: std::collections::HashMap::new(),
-                movement_cooldowns: HashMap::new(),
+                spawn_order,
+                homes: HashMap::new(),
+                respawns: BTreeMap::new(),
+                bomb_manager: BombManager::new(),
+                collapse,
+                eliminated: Vec::new(),
+                started: None,
             },
             rx,
             events,
@@ -89,9 +142,20 @@ impl Engine {
         grid: Arc<RwLock<GameGrid>>,
         events: Arc<EventBus>,
     ) -> (Self, watch::Receiver<GridDelta>) {
+        let (spawn_order, collapse) = {
+            let grid = grid.read().expect("grid lock poisoned");
+            let collapse = config
+                .rules
+                .sudden_death
+                .as_ref()
+                .map(|rules| CollapseSchedule::new(&grid, rules));
+            (spawn_order(&config, &grid), collapse)
+        };
         let (tx, rx) = watch::channel(GridDelta::None);
         let filter = EventFilter::new(|e| matches!(e, Event::Bot(_)));
         let (_id, cmd_rx) = events.subscribe_with_filter(Some(filter));
+        let filter = EventFilter::new(|e| matches!(e, Event::Grid(_)));
+        let (_id, grid_rx) = events.subscribe_with_filter(Some(filter));
         let bot_manager = BotManager::new();
         (
             Self {
@@ -106,285 +170,629 @@ impl Engine {
                 bot_manager,
                 bots: Vec::new(),
                 bot_command_rx: cmd_rx,
+                grid_rx,
                 tick: 0,
                 bot_status: std::collections::HashMap::new(),
-                movement_cooldowns: HashMap::new(),
+                spawn_order,
+                homes: HashMap::new(),
+                respawns: BTreeMap::new(),
+                bomb_manager: BombManager::new(),
+                collapse,
+                eliminated: Vec::new(),
+                started: None,
             },
             rx,
         )
     }
 
-    /// Advances the game by a single tick by running all registered systems.
+    /// Advances the game by a single lock-step tick.
+    ///
+    /// Registered systems run first. Every bot is then sent a numbered
+    /// [`GameEvent::TickRequested`] and the engine waits until each bot has
+    /// answered or its `decision_timeout` has elapsed. The collected decisions
+    /// are resolved together in ascending bot id order, so the outcome does not
+    /// depend on how the bot threads happened to be scheduled.
+    ///
+    /// Explosions hit agents both before and after the moves, so stepping off
+    /// a bomb as it goes off does not save an agent, and neither does walking
+    /// into lingering flames.
+    ///
+    /// With sudden death enabled the arena then collapses as scheduled, before
+    /// waiting agents respawn.
     pub async fn tick(&mut self) -> Result<(), EngineError> {
+        self.started.get_or_insert_with(Instant::now);
         self.scheduler.run().await;
         self.events.process();
-        
-        // Send a tick event to prompt bots to make decisions
-        // This ensures bots get regular opportunities to think and act
-        let tick_delta = GridDelta::None; // Use None as a "thinking prompt"
-        
-        // Broadcast to all subscribers (including bots)
-        self.events.broadcast(Event::Grid(tick_delta.clone()));
-        
-        // Also send via the delta channel for any other listeners
-        let _ = self.delta_tx.send(tick_delta);
-        
-        // Process all bot events directly from the event bus
-        // This ensures we get events from ALL bots, not just from a subscription
-        let mut _event_count = 0;
-        
-        // Process any events that might be in the subscription first
-        while let Ok(Event::Bot(cmd)) = self.bot_command_rx.try_recv() {
-            _event_count += 1;
-            match &cmd {
-                BotEvent::Status { bot_id, status } => {
-                    self.bot_status.insert(*bot_id, status.clone());
-                }
-                BotEvent::Decision { bot_id, .. } | BotEvent::Error { bot_id, .. } => {
-                    if let Err(e) = self.handle_bot_command(cmd.clone()) {
-                        self.events.emit(
-                            Event::Bot(BotEvent::Error {
-                                bot_id: *bot_id,
-                                message: e.to_string(),
-                            }),
-                            EventPriority::Normal,
-                        );
-                    }
-                }
-            }
-        }
-        
-        // CRITICAL: Process the event bus to ensure ALL events are delivered
-        // This is the key fix - we need to process the event bus to get events from all bots
-        self.events.process();
-        
-        // Process bot decisions from the event bus
-        // The bots send their decisions via events, not via the channel
-        let mut bot_events = Vec::new();
-        self.events.collect_events(&mut bot_events, |event| {
-            matches!(event, Event::Bot(BotEvent::Decision { .. }))
-        });
-        
-        for event in bot_events {
-            if let Event::Bot(cmd) = event {
-                match &cmd {
-                    BotEvent::Status { bot_id, status } => {
-                        self.bot_status.insert(*bot_id, status.clone());
-                    }
-                    BotEvent::Decision { bot_id, .. } | BotEvent::Error { bot_id, .. } => {
-                        if let Err(e) = self.handle_bot_command(cmd.clone()) {
-                            self.events.emit(
-                                Event::Bot(BotEvent::Error {
-                                    bot_id: *bot_id,
-                                    message: e.to_string(),
-                                }),
-                                EventPriority::Normal,
-                            );
-                        }
-                    }
-                }
-            }
+        {
+            let grid = self.grid.read().expect("grid lock poisoned");
+            self.bomb_manager
+                .retain(|b| grid.bombs().iter().any(|bomb| bomb.id == b.id));
         }
-        
 
-        
+        let tick = self.tick + 1;
+        self.resolve_hits(tick);
+        let decisions = self.collect_decisions(tick).await;
+        self.resolve_decisions(decisions);
+        self.resolve_hits(tick);
+        self.collapse_arena(tick);
+        self.respawn_agents(tick);
+
         let grid = self
             .grid
             .read()
             .map_err(|e| EngineError::GridLockPoisoned(e.to_string()))?;
         self.determinism_checker.record(&grid);
         drop(grid);
-        self.tick += 1;
+        self.record_grid_events();
+        self.tick = tick;
         self.events
             .broadcast(Event::Game(GameEvent::TickCompleted { tick: self.tick }));
         Ok(())
     }
 
-    fn handle_bot_command(&mut self, cmd: BotEvent) -> Result<(), BotError> {
-        match cmd {
-            BotEvent::Decision { bot_id, decision } => {
-                println!("Processing decision for bot {}: {:?}", bot_id, decision);
-                match decision {
-                    BotDecision::Wait => Ok(()),
-                    BotDecision::Move(direction) => {
-                        // Check movement cooldown (200ms between movements)
-                        let now = std::time::Instant::now();
-                        let default_time = std::time::Instant::now();
-                        let last_move = self.movement_cooldowns.get(&bot_id).unwrap_or(&default_time);
-                        if now.duration_since(*last_move).as_millis() < 200 {
-                            println!("Bot {} is in movement cooldown", bot_id);
-                            return Ok(()); // Still in cooldown
-                        }
-                        
-                        let mut grid = self.grid.write().expect("grid lock poisoned");
-                        
-                        // Find the agent and calculate new position
-                        let mut new_position = None;
-                        if let Some(agent) = grid.agents().iter().find(|a| a.id == bot_id) {
-                            let (mut x, mut y) = agent.position;
-                            let old_pos = (x, y);
-                            println!("Bot {} current position: ({}, {})", bot_id, x, y);
-                            
-                            // Calculate new position
-                            match direction {
-                                common::Direction::Up => y = y.saturating_sub(1),
-                                common::Direction::Down => y = y.saturating_add(1).min(self.config.height as u16 - 1),
-                                common::Direction::Left => x = x.saturating_sub(1),
-                                common::Direction::Right => x = x.saturating_add(1).min(self.config.width as u16 - 1),
-                            }
-                            println!("Bot {} new position: ({}, {})", bot_id, x, y);
-                            
-                            // Only move if position actually changed and is valid
-                            if (x, y) != old_pos && self.is_position_walkable(&grid, (x, y)) {
-                                println!("Bot {} position is walkable", bot_id);
-                                new_position = Some((x, y));
-                            } else {
-                                println!("Bot {} position ({}, {}) is not walkable", bot_id, x, y);
-                                if (x, y) == old_pos {
-                                    println!("  Position didn't change");
-                                }
-                                // Let's check what's at this position
-                                if x < self.config.width as u16 && y < self.config.height as u16 {
-                                    let tiles = grid.tiles();
-                                    let index = (y as usize) * self.config.width + (x as usize);
-                                    if index < tiles.len() {
-                                        println!("  Tile at ({}, {}): {:?}", x, y, tiles[index]);
-                                    }
-                                }
-                                // Check for other agents
-                                for agent in grid.agents() {
-                                    if agent.position == (x, y) {
-                                        println!("  Agent {} is at position ({}, {})", agent.id, x, y);
-                                    }
-                                }
-                            }
-                        }
-                        
-                        // Apply the movement if valid
-                        if let Some(new_pos) = new_position {
-                            println!("Moving bot {} to ({}, {})", bot_id, new_pos.0, new_pos.1);
-                            if let Some(agent) = grid.agents_mut().iter_mut().find(|a| a.id == bot_id) {
-                                agent.position = new_pos;
-                                let delta = GridDelta::MoveAgent(bot_id, new_pos);
-                                self.replay_recorder.record(delta.clone());
-                                let _ = self.delta_tx.send(delta.clone());
-                                self.events.broadcast(Event::Grid(delta));
-                                
-                                // Update movement cooldown
-                                self.movement_cooldowns.insert(bot_id, now);
-                            }
-                        }
-                        Ok(())
-                    }
-                    BotDecision::PlaceBomb => {
-                        println!("Bot {} placing bomb", bot_id);
-                        let mut grid = self.grid.write().expect("grid lock poisoned");
-                        if let Some(agent) = grid.agents_mut().iter_mut().find(|a| a.id == bot_id) {
-                            // Check if agent has bombs left
-                            if agent.bombs_left == 0 {
-                                println!("Bot {} has no bombs left", bot_id);
-                                drop(grid);
-                                return Ok(()); // Can't place bomb, no bombs left
-                            }
-                        
-                            let position = agent.position;
-                            
-                            // Decrement bombs left
-                            agent.bombs_left -= 1;
-                            
-                            // Create bomb for the state grid (for display/tracking)
-                            let state_bomb = Bomb::new(bot_id, position, 3, 1);
-                            let delta = GridDelta::AddBomb(state_bomb);
-                            grid.apply_delta(delta.clone());
-                            drop(grid);
-                            
-                            self.replay_recorder.record(delta.clone());
-                            let _ = self.delta_tx.send(delta.clone());
-                            self.events.broadcast(Event::Grid(delta));
-                            
-                            // Also broadcast bomb placement event for the bomb system to handle
-                            self.events.broadcast(Event::bomb(events::events::BombEvent::Placed {
-                                agent_id: bot_id,
-                                position,
-                            }));
-                        }
-                        Ok(())
-                    }
+    /// Request decisions for `tick` and wait for the answers.
+    ///
+    /// Bots that do not answer within their own `decision_timeout` simply have
+    /// no decision this tick. Decisions tagged with another tick are dropped.
+    async fn collect_decisions(&mut self, tick: u64) -> BTreeMap<BotId, BotDecision> {
+        let mut decisions = BTreeMap::new();
+        if !self.bots.is_empty() {
+            self.events
+                .broadcast(Event::Game(GameEvent::TickRequested { tick }));
+        }
+
+        let started = Instant::now();
+        loop {
+            self.events.process();
+            self.drain_bot_events(tick, &mut decisions);
+            let waiting = self.bots.iter().any(|bot| {
+                !decisions.contains_key(&bot.id) && started.elapsed() < bot.decision_timeout
+            });
+            if !waiting {
+                break;
+            }
+            tokio::time::sleep(DECISION_POLL_INTERVAL).await;
+        }
+
+        for bot in &self.bots {
+            if !decisions.contains_key(&bot.id) {
+                debug!(
+                    "bot {} missed the decision deadline for tick {}",
+                    bot.id, tick
+                );
+            }
+        }
+        decisions
+    }
+
+    /// Drain pending bot events, keeping the first decision per bot for `tick`.
+    fn drain_bot_events(&mut self, tick: u64, decisions: &mut BTreeMap<BotId, BotDecision>) {
+        while let Ok(event) = self.bot_command_rx.try_recv() {
+            match event {
+                Event::Bot(BotEvent::Decision {
+                    bot_id,
+                    tick: decided_for,
+                    decision,
+                }) => {
+                    if decided_for == tick {
+                        decisions.entry(bot_id).or_insert(decision);
+                    } else {
+                        debug!(
+                            "dropping decision of bot {} for tick {} during tick {}",
+                            bot_id, decided_for, tick
+                        );
                     }
                 }
+                Event::Bot(BotEvent::Status { bot_id, status }) => {
+                    self.bot_status.insert(bot_id, status);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Apply the decisions collected for a tick.
+    ///
+    /// Bombs are placed first, at the positions agents held when the tick
+    /// started, and remote bombs are detonated next. Moves are then resolved
+    /// in steps: every step settles all remaining moves together, see
+    /// [`resolve_moves`], and an agent keeps stepping in its direction while
+    /// its speed allows and its last step succeeded. Each step of each intent is reported as a
+    /// [`GameEvent::MoveResolved`], and agents collect power-ups on every
+    /// tile they enter. Agents able to kick set bombs in their way sliding
+    /// instead of walking into them.
+    fn resolve_decisions(&mut self, decisions: BTreeMap<BotId, BotDecision>) {
+        for (&bot_id, decision) in &decisions {
+            if *decision == BotDecision::PlaceBomb {
+                self.place_bomb(bot_id);
+            }
+        }
+        for (&bot_id, decision) in &decisions {
+            if let BotDecision::Detonate(bomb_id) = decision {
+                self.detonate(bot_id, *bomb_id);
+            }
+        }
+        let mut intents: BTreeMap<BotId, Direction> = decisions
+            .iter()
+            .filter_map(|(&bot_id, decision)| match decision {
+                BotDecision::Move(direction) => Some((bot_id, *direction)),
+                _ => None,
+            })
+            .collect();
+        for step in 0u8.. {
+            {
+                let grid = self.grid.read().expect("grid lock poisoned");
+                intents.retain(|id, _| grid.agents().iter().any(|a| a.id == *id && a.speed > step));
+            }
+            if intents.is_empty() {
+                break;
+            }
+            self.kick_bombs(&intents);
+            let resolved = {
+                let grid = self.grid.read().expect("grid lock poisoned");
+                resolve_moves(
+                    &grid,
+                    &intents,
+                    &self.config.rules.movement,
+                    self.config.seed.wrapping_add(step as u64),
+                    self.tick,
+                )
+            };
+            for mv in resolved {
+                if mv.to != mv.from {
+                    let delta = GridDelta::MoveAgent(mv.bot_id, mv.to);
+                    self.grid
+                        .write()
+                        .expect("grid lock poisoned")
+                        .apply_delta(delta.clone());
+                    self.publish_delta(delta);
+                    self.collect_powerup(mv.bot_id, mv.to);
+                } else {
+                    debug!(
+                        "bot {} did not move {:?} from {:?}: {:?}",
+                        mv.bot_id, mv.direction, mv.from, mv.outcome
+                    );
+                    intents.remove(&mv.bot_id);
+                }
+                self.events.broadcast(Event::Game(GameEvent::MoveResolved {
+                    entity_id: mv.bot_id,
+                    from: mv.from,
+                    intended: mv.intended,
+                    to: mv.to,
+                    outcome: mv.outcome,
+                }));
+            }
+        }
+    }
+
+    /// Set bombs sliding that agents able to kick are walking into.
+    ///
+    /// The kicker itself stays put; the bomb starts moving on the next tick.
+    fn kick_bombs(&mut self, intents: &BTreeMap<BotId, Direction>) {
+        let mut grid = self.grid.write().expect("grid lock poisoned");
+        let mut kicked = Vec::new();
+        for (&bot_id, &direction) in intents {
+            let Some(agent) = grid.agents().iter().find(|a| a.id == bot_id && a.kick) else {
+                continue;
+            };
+            let Some(target) = step(&grid, agent.position, direction) else {
+                continue;
+            };
+            let Some(bomb) = grid
+                .bombs()
+                .iter()
+                .find(|b| b.position == target && b.sliding.is_none())
+                .cloned()
+            else {
+                continue;
+            };
+            let bomb_id = bomb.id;
+            let delta = GridDelta::UpdateBomb(Bomb {
+                sliding: Some(direction),
+                ..bomb
+            });
+            grid.apply_delta(delta.clone());
+            kicked.push((delta, bomb_id, bot_id, direction));
+        }
+        drop(grid);
+        for (delta, bomb_id, agent_id, direction) in kicked {
+            self.publish_delta(delta);
+            self.events.broadcast(Event::bomb(BombEvent::Kicked {
+                bomb_id,
+                agent_id,
+                direction,
+            }));
+        }
+    }
+
+    /// Hand the power-up at `position`, if any, to the agent standing on it.
+    fn collect_powerup(&mut self, bot_id: BotId, position: (u16, u16)) {
+        let mut grid = self.grid.write().expect("grid lock poisoned");
+        let Some(Tile::PowerUp(kind)) = grid.tile(position.0 as usize, position.1 as usize) else {
+            return;
+        };
+        let Some(mut agent) = grid.agents().iter().find(|a| a.id == bot_id).cloned() else {
+            return;
+        };
+        let live_bombs = grid.bombs().iter().filter(|b| b.owner == bot_id).count();
+        apply_powerup(&mut agent, kind, live_bombs, &self.config.rules.powerups);
+        let deltas = [
+            GridDelta::SetTile {
+                x: position.0 as usize,
+                y: position.1 as usize,
+                tile: Tile::Empty,
             },
-            BotEvent::Error { .. } => Ok(()),
-            BotEvent::Status { bot_id, status } => {
-                self.bot_status.insert(bot_id, status);
-                Ok(())
+            GridDelta::UpdateAgent(agent),
+        ];
+        for delta in &deltas {
+            grid.apply_delta(delta.clone());
+        }
+        drop(grid);
+        for delta in deltas {
+            self.publish_delta(delta);
+        }
+        self.events
+            .broadcast(Event::bomb(BombEvent::PowerUpCollected {
+                agent_id: bot_id,
+                power_type: kind,
+            }));
+    }
+
+    /// Take a life from every vulnerable agent standing in an explosion.
+    ///
+    /// Hit agents leave the grid. Those with lives left wait
+    /// `respawn_delay` ticks for [`Engine::respawn_agents`]; the rest are
+    /// eliminated.
+    fn resolve_hits(&mut self, tick: u64) {
+        let mut grid = self.grid.write().expect("grid lock poisoned");
+        let hit: Vec<AgentState> = grid
+            .agents()
+            .iter()
+            .filter(|a| a.is_vulnerable(tick))
+            .filter(|a| {
+                grid.tile(a.position.0 as usize, a.position.1 as usize) == Some(Tile::Explosion)
+            })
+            .cloned()
+            .collect();
+        let mut removed = Vec::with_capacity(hit.len());
+        for mut agent in hit {
+            let delta = GridDelta::RemoveAgent(agent.id);
+            grid.apply_delta(delta.clone());
+            removed.push(delta);
+            agent.lives = agent.lives.saturating_sub(1);
+            self.events.broadcast(Event::Game(GameEvent::AgentHit {
+                entity_id: agent.id,
+                position: agent.position,
+                lives_left: agent.lives,
+            }));
+            if agent.lives == 0 {
+                self.eliminated.push((agent.id, tick));
+                self.events
+                    .broadcast(Event::Game(GameEvent::AgentEliminated {
+                        entity_id: agent.id,
+                        tick,
+                    }));
+                continue;
             }
+            // Bombs still ticking return to the agent's stock when they go
+            // off, which cannot happen while it is off the grid; count them
+            // now and take back the ones still live when it respawns.
+            let live = grid.bombs().iter().filter(|b| b.owner == agent.id).count();
+            agent.bombs_left = agent.bombs_left.saturating_add(live as u8);
+            let at = tick + self.config.rules.respawn_delay as u64;
+            self.respawns.insert(agent.id, (at, agent));
+        }
+        drop(grid);
+        for delta in removed {
+            self.publish_delta(delta);
         }
     }
 
-    /// Spawn a bot managed by the engine.
-                        agent.bombs_left -= 1;
-                        
-                        // Create bomb for the state grid (for display/tracking)
-                        let state_bomb = Bomb::new(bot_id, position, 3, 1);
-                        let delta = GridDelta::AddBomb(state_bomb);
-                        grid.apply_delta(delta.clone());
-                        drop(grid);
-                        
-                        self.replay_recorder.record(delta.clone());
-                        let _ = self.delta_tx.send(delta.clone());
-                        self.events.broadcast(Event::Grid(delta));
-                        
-                        // Also broadcast bomb placement event for the bomb system to handle
-                        self.events.broadcast(Event::bomb(events::events::BombEvent::Placed {
-                            agent_id: bot_id,
-                            position,
-                        }));
+    /// Announce and carry out the sudden-death collapses due on `tick`.
+    ///
+    /// A collapsing tile becomes a wall. An agent standing on it is
+    /// eliminated whatever lives it has left, and a bomb lying on it is
+    /// removed and returned to its owner's stock.
+    fn collapse_arena(&mut self, tick: u64) {
+        let Some(schedule) = self.collapse.as_mut() else {
+            return;
+        };
+        for &(at, position) in schedule.warnings(tick) {
+            self.events
+                .broadcast(Event::Game(GameEvent::TileCollapsing {
+                    position,
+                    tick: at,
+                }));
+        }
+        for position in schedule.collapses(tick) {
+            let mut grid = self.grid.write().expect("grid lock poisoned");
+            let mut deltas = Vec::new();
+            let crushed = grid
+                .agents()
+                .iter()
+                .find(|a| a.position == position)
+                .map(|a| a.id);
+            if let Some(id) = crushed {
+                deltas.push(GridDelta::RemoveAgent(id));
+            }
+            let bombs: Vec<Bomb> = grid
+                .bombs()
+                .iter()
+                .filter(|b| b.position == position)
+                .cloned()
+                .collect();
+            for bomb in bombs {
+                deltas.push(GridDelta::RemoveBomb(bomb.id));
+                if let Some(owner) = grid.agents().iter().find(|a| a.id == bomb.owner) {
+                    if Some(owner.id) != crushed {
+                        let mut owner = owner.clone();
+                        owner.bombs_left = owner.bombs_left.saturating_add(1);
+                        deltas.push(GridDelta::UpdateAgent(owner));
                     }
                 }
-            },
-            BotEvent::Error { .. } => Ok(()),
-            BotEvent::Status { bot_id, status } => {
-                self.bot_status.insert(bot_id, status);
-                Ok(())
+            }
+            deltas.push(GridDelta::SetTile {
+                x: position.0 as usize,
+                y: position.1 as usize,
+                tile: Tile::Wall,
+            });
+            for delta in &deltas {
+                grid.apply_delta(delta.clone());
+            }
+            drop(grid);
+            for delta in deltas {
+                self.publish_delta(delta);
+            }
+            self.events
+                .broadcast(Event::Game(GameEvent::TileCollapsed { position, tick }));
+            if let Some(entity_id) = crushed {
+                self.eliminated.push((entity_id, tick));
+                self.events
+                    .broadcast(Event::Game(GameEvent::AgentEliminated { entity_id, tick }));
+            }
+        }
+    }
+
+    /// Return waiting agents whose respawn delay has passed.
+    ///
+    /// An agent goes back to its own spawn point, or to the first other one
+    /// that is free, and cannot be hit for `invulnerability_ticks`. With every
+    /// spawn point blocked it waits for the next tick, unless sudden death has
+    /// walled them all in, which eliminates it.
+    fn respawn_agents(&mut self, tick: u64) {
+        let due: Vec<BotId> = self
+            .respawns
+            .iter()
+            .filter(|(_, (at, _))| *at <= tick)
+            .map(|(&id, _)| id)
+            .collect();
+        for bot_id in due {
+            let mut grid = self.grid.write().expect("grid lock poisoned");
+            let free = |pos: &(u16, u16)| {
+                is_tile_walkable(&grid, *pos) && grid.agents().iter().all(|a| a.position != *pos)
+            };
+            let walled =
+                |&(x, y): &(u16, u16)| grid.tile(x as usize, y as usize) == Some(Tile::Wall);
+            if self.collapse.is_some()
+                && self
+                    .homes
+                    .get(&bot_id)
+                    .into_iter()
+                    .chain(&self.spawn_order)
+                    .all(walled)
+            {
+                drop(grid);
+                self.respawns.remove(&bot_id);
+                self.eliminated.push((bot_id, tick));
+                self.events
+                    .broadcast(Event::Game(GameEvent::AgentEliminated {
+                        entity_id: bot_id,
+                        tick,
+                    }));
+                continue;
+            }
+            let Some(position) = self
+                .homes
+                .get(&bot_id)
+                .into_iter()
+                .chain(&self.spawn_order)
+                .copied()
+                .find(free)
+            else {
+                continue;
+            };
+            let (_, mut agent) = self.respawns.remove(&bot_id).expect("respawn is due");
+            let live = grid.bombs().iter().filter(|b| b.owner == bot_id).count();
+            agent.bombs_left = agent.bombs_left.saturating_sub(live as u8);
+            agent.position = position;
+            agent.invulnerable_until = tick + self.config.rules.invulnerability_ticks as u64;
+            let delta = GridDelta::AddAgent(agent);
+            grid.apply_delta(delta.clone());
+            drop(grid);
+            self.publish_delta(delta);
+            self.events
+                .broadcast(Event::Game(GameEvent::AgentRespawned {
+                    entity_id: bot_id,
+                    position,
+                }));
+        }
+    }
+
+    /// Place a bomb under the agent of `bot_id`.
+    ///
+    /// The bomb uses the configured `bomb_timer` and the agent's power, pierce
+    /// and remote capabilities. Agents without bombs left, or standing on a
+    /// tile that already holds a bomb, place nothing.
+    fn place_bomb(&mut self, bot_id: BotId) {
+        let mut grid = self.grid.write().expect("grid lock poisoned");
+        let Some(agent) = grid.agents().iter().find(|a| a.id == bot_id).cloned() else {
+            return;
+        };
+        if agent.bombs_left == 0 {
+            debug!("bot {} has no bombs left", bot_id);
+            return;
+        }
+        if !grid.can_place_bomb(agent.position) {
+            debug!("bot {} cannot place a bomb at {:?}", bot_id, agent.position);
+            return;
+        }
+        let timer = u8::try_from(self.config.rules.bomb_timer).unwrap_or(u8::MAX);
+        let bomb_id = grid.allocate_bomb_id();
+        let bomb = Bomb {
+            pierce: agent.pierce,
+            remote: agent.remote,
+            ..Bomb::new(bomb_id, bot_id, agent.position, timer, agent.power)
+        };
+        let deltas = [
+            GridDelta::UpdateAgent(AgentState {
+                bombs_left: agent.bombs_left - 1,
+                ..agent.clone()
+            }),
+            GridDelta::AddBomb(bomb.clone()),
+        ];
+        for delta in &deltas {
+            grid.apply_delta(delta.clone());
+        }
+        drop(grid);
+        for delta in deltas {
+            self.publish_delta(delta);
+        }
+        self.bomb_manager.add_bomb(bombs::Bomb::from(&bomb));
+
+        self.events.broadcast(Event::Game(GameEvent::BombPlaced {
+            entity_id: bot_id,
+            bomb_id,
+            position: agent.position,
+            power: agent.power,
+        }));
+        self.events.broadcast(Event::bomb(BombEvent::Placed {
+            bomb_id,
+            agent_id: bot_id,
+            position: agent.position,
+        }));
+    }
+
+    /// Set off a remote bomb of `bot_id`, the oldest one when `bomb_id` is `None`.
+    ///
+    /// Bombs owned by other agents or not armed for remote detonation are left
+    /// alone. A detonated bomb goes off when the bomb system next runs.
+    fn detonate(&mut self, bot_id: BotId, bomb_id: Option<BombId>) {
+        let mut grid = self.grid.write().expect("grid lock poisoned");
+        let bomb = match bomb_id {
+            Some(id) => grid.bombs().iter().find(|b| b.id == id),
+            None => grid
+                .bombs()
+                .iter()
+                .filter(|b| b.owner == bot_id && b.remote)
+                .min_by_key(|b| b.id),
+        };
+        let Some(bomb) = bomb.cloned() else {
+            debug!("bot {} has no bomb {:?} to detonate", bot_id, bomb_id);
+            return;
+        };
+        if bomb.owner != bot_id {
+            debug!(
+                "bot {} cannot detonate bomb {:?} of bot {}",
+                bot_id, bomb.id, bomb.owner
+            );
+            return;
+        }
+        if let Err(e) = self.bomb_manager.detonate_remote(bomb.id) {
+            debug!("bot {} cannot detonate: {}", bot_id, e);
+            return;
+        }
+        self.bomb_manager.remove_bomb(bomb.id);
+        let bomb_id = bomb.id;
+        let delta = GridDelta::UpdateBomb(Bomb { timer: 0, ..bomb });
+        grid.apply_delta(delta.clone());
+        drop(grid);
+        self.publish_delta(delta);
+        self.events.broadcast(Event::bomb(BombEvent::Detonated {
+            bomb_id,
+            agent_id: bot_id,
+        }));
+    }
+
+    /// Broadcast a delta to all listeners, the replay recorder included.
+    fn publish_delta(&self, delta: GridDelta) {
+        let _ = self.delta_tx.send(delta.clone());
+        self.events.broadcast(Event::Grid(delta));
+    }
+
+    /// Hand every grid change broadcast since the last call to the replay
+    /// recorder, whether the engine or a system published it.
+    fn record_grid_events(&self) {
+        for event in self.grid_rx.try_iter() {
+            if let Event::Grid(delta) = event {
+                self.replay_recorder.record(delta);
             }
         }
     }
 
     /// Spawn a bot managed by the engine.
+    ///
+    /// The bot's agent is placed on the first spawn point, in assignment
+    /// order, that no agent occupies. Fails with [`BotError::NoSpawnPoint`]
+    /// when every spawn point is taken.
     pub fn spawn_bot(&mut self, config: BotConfig) -> Result<BotId, BotError> {
+        let position = self.free_spawn_point()?;
         let handle = self
             .bot_manager
             .spawn_bot(config, Arc::clone(&self.events))?;
+        Ok(self.place_bot(handle, position))
+    }
+
+    /// Spawn a bot running in another process behind `connection`.
+    ///
+    /// The bot is welcomed with the current grid and placed like
+    /// [`Engine::spawn_bot`] places local bots; from then on the engine
+    /// treats it exactly like them.
+    pub fn spawn_remote_bot(
+        &mut self,
+        connection: RemoteConnection,
+        decision_timeout: Duration,
+    ) -> Result<BotId, BotError> {
+        let position = self.free_spawn_point()?;
+        let grid = GridSnapshot::from(&*self.grid.read().expect("grid lock poisoned"));
+        let handle = self.bot_manager.spawn_remote(
+            connection,
+            decision_timeout,
+            grid,
+            Arc::clone(&self.events),
+        )?;
+        Ok(self.place_bot(handle, position))
+    }
+
+    /// First spawn point, in assignment order, that no agent occupies.
+    fn free_spawn_point(&self) -> Result<(u16, u16), BotError> {
+        let grid = self.grid.read().expect("grid lock poisoned");
+        self.spawn_order
+            .iter()
+            .copied()
+            .find(|&spawn| grid.agents().iter().all(|a| a.position != spawn))
+            .ok_or(BotError::NoSpawnPoint(self.spawn_order.len()))
+    }
+
+    /// Take over `handle` and add its agent at `position`.
+    fn place_bot(&mut self, handle: BotHandle, position: (u16, u16)) -> BotId {
         let id = handle.id;
         self.bots.push(handle);
-        
-        // Calculate spawn position based on bot ID to avoid overlapping
-        // Spread 8 bots across the larger map in a grid pattern
-        // Each spawn position should have a 3x3 cleared area
-        let spawn_positions = [
-            (3u16, 3u16),        // Top-left
-            ((self.config.width / 2) as u16, 3u16),  // Top-center
-            ((self.config.width - 4) as u16, 3u16),  // Top-right
-            (3u16, (self.config.height / 2) as u16), // Middle-left
-            ((self.config.width - 4) as u16, (self.config.height / 2) as u16), // Middle-right
-            (3u16, (self.config.height - 4) as u16), // Bottom-left
-            ((self.config.width / 2) as u16, (self.config.height - 4) as u16), // Bottom-center
-            ((self.config.width - 4) as u16, (self.config.height - 4) as u16), // Bottom-right
-        ];
-        let position = spawn_positions[id % spawn_positions.len()];
-        
-        // Initialize movement cooldown for this bot
-        self.movement_cooldowns.insert(id, std::time::Instant::now());
-        
-        let agent = state::components::AgentState::new(id, position);
+
+        self.homes.insert(id, position);
+
+        let agent = AgentState {
+            lives: self.config.rules.starting_lives,
+            ..AgentState::new(id, position)
+        };
         let delta = GridDelta::AddAgent(agent);
-        self.grid.write().expect("grid lock poisoned").apply_delta(delta.clone());
-        self.replay_recorder.record(delta.clone());
-        let _ = self.delta_tx.send(delta.clone());
-        self.events.broadcast(Event::Grid(delta));
-        println!("🎯 Engine spawned bot {} at position {:?}", id, position);
-        
-        Ok(id)
+        self.grid
+            .write()
+            .expect("grid lock poisoned")
+            .apply_delta(delta.clone());
+        self.publish_delta(delta);
+        debug!("engine spawned bot {} at position {:?}", id, position);
+
+        id
     }
 
     /// Remove a bot from the engine.
@@ -392,17 +800,44 @@ impl Engine {
         if let Some(pos) = self.bots.iter().position(|b| b.id == bot_id) {
             let handle = self.bots.remove(pos);
             handle.abort();
+            self.respawns.remove(&bot_id);
             Ok(())
         } else {
             Err(BotError::NotFound)
         }
     }
 
+    /// Stop every bot of the match and wait for their threads to exit.
+    ///
+    /// Bot threads exit once they receive [`SystemEvent::EngineStopped`].
+    pub fn shutdown(&mut self) {
+        if self.bots.is_empty() {
+            return;
+        }
+        let bots: Vec<BotHandle> = self.bots.drain(..).collect();
+        for handle in &bots {
+            handle.abort();
+        }
+        self.events
+            .broadcast(Event::System(SystemEvent::EngineStopped));
+        for handle in bots {
+            let id = handle.id;
+            if handle.join().is_none() {
+                warn!("bot {} panicked", id);
+            }
+        }
+    }
+
     /// Access the shared game grid.
     pub fn grid(&self) -> Arc<RwLock<GameGrid>> {
         Arc::clone(&self.grid)
     }
 
+    /// Number of ticks completed so far.
+    pub fn current_tick(&self) -> u64 {
+        self.tick
+    }
+
     /// Access the engine configuration.
     pub fn config(&self) -> &EngineConfig {
         &self.config
@@ -415,40 +850,13 @@ impl Engine {
 
     /// Start recording a replay.
     pub fn start_replay_recording(&mut self) {
+        self.record_grid_events();
         self.replay_recorder.start();
     }
-    
-    /// Check if a position is walkable (not a wall or obstacle)
-    fn is_position_walkable(&self, grid: &GameGrid, pos: (u16, u16)) -> bool {
-        use state::Tile;
-        
-        // Bounds checking
-        if pos.0 >= self.config.width as u16 || pos.1 >= self.config.height as u16 {
-            return false;
-        }
-        
-        // Check if there's another agent at this position
-        for agent in grid.agents() {
-            if agent.position == pos {
-                return false;
-            }
-        }
-        
-        // Check tile type
-        let tiles = grid.tiles();
-        let index = (pos.1 as usize) * self.config.width + (pos.0 as usize);
-        if index < tiles.len() {
-            match tiles[index] {
-                Tile::Empty | Tile::PowerUp => true,
-                Tile::Wall | Tile::SoftCrate | Tile::Explosion => false,
-            }
-        } else {
-            false
-        }
-    }
 
     /// Stop recording and return the replay.
     pub fn stop_replay_recording(&mut self) -> Replay {
+        self.record_grid_events();
         self.replay_recorder.stop()
     }
 
@@ -487,14 +895,12 @@ impl Engine {
         let grid = Arc::clone(&self.grid);
         let tx = self.delta_tx.clone();
         let sys_clone = Arc::clone(&sys);
-        let recorder = self.replay_recorder.clone();
         let events = Arc::clone(&self.events);
         self.scheduler.add_task(name, deps, parallel, move || {
             let mut s = sys_clone.lock().expect("system lock poisoned");
             if let Some(delta) = s.run(&grid, events.as_ref()) {
                 let mut g = grid.write().expect("grid lock poisoned");
                 g.apply_delta(delta.clone());
-                recorder.record(delta.clone());
                 let _ = tx.send(delta.clone());
                 events.broadcast(Event::Grid(delta));
             }
@@ -506,40 +912,90 @@ impl Engine {
     pub fn bot_statuses(&self) -> &std::collections::HashMap<usize, String> {
         &self.bot_status
     }
-    
-    /// Check if the game has ended and return the winner if applicable.
-    pub fn check_game_end(&self) -> Option<usize> {
-        let grid = self.grid.read().ok()?;
-        let agents = grid.agents();
-        
-        // Game ends when only one agent remains
-        if agents.len() == 1 {
-            Some(agents[0].id)
-        } else if agents.is_empty() {
-            // All agents eliminated (tie)
-            Some(usize::MAX) // Use MAX to indicate tie
-        } else {
-            None // Game still ongoing
+
+    /// Check whether the match is over and how it ended.
+    ///
+    /// Agents waiting to respawn are still in the game. A lone agent left
+    /// wins; when the last agents all go down on the same tick the match is a
+    /// draw between them. Otherwise the match goes on until one of the
+    /// configured [`MatchLimits`](crate::config::MatchLimits) runs out.
+    pub fn check_game_end(&self) -> Option<MatchOutcome> {
+        let alive = self.remaining_agents();
+        if alive == 1 {
+            return self.ranking().first().copied().map(MatchOutcome::Winner);
+        }
+        if alive == 0 {
+            let last = self.eliminated.last().map(|&(_, tick)| tick);
+            let mut together: Vec<BotId> = self
+                .eliminated
+                .iter()
+                .filter(|&&(_, tick)| Some(tick) == last)
+                .map(|&(id, _)| id)
+                .collect();
+            together.sort_unstable();
+            return Some(MatchOutcome::Draw(together));
+        }
+        let limits = &self.config.rules.limits;
+        let out_of_ticks = limits.max_ticks.is_some_and(|max| self.tick >= max);
+        let out_of_time = match (limits.max_seconds, self.started) {
+            (Some(max), Some(started)) => started.elapsed() >= Duration::from_secs(max),
+            _ => false,
+        };
+        if out_of_ticks || out_of_time {
+            return Some(MatchOutcome::Timeout {
+                ranking: self.ranking(),
+            });
         }
+        None
     }
-    
-    /// Get the number of remaining agents.
+
+    /// Every agent of the match, best first.
+    ///
+    /// Agents still in the game come first, ordered by lives left and then
+    /// by id. Eliminated agents follow, the last to go out first.
+    pub fn ranking(&self) -> Vec<BotId> {
+        let grid = self.grid.read().expect("grid lock poisoned");
+        let mut alive: Vec<(u8, BotId)> = grid
+            .agents()
+            .iter()
+            .map(|a| (a.lives, a.id))
+            .chain(self.respawns.values().map(|(_, a)| (a.lives, a.id)))
+            .collect();
+        alive.sort_by_key(|&(lives, id)| (std::cmp::Reverse(lives), id));
+        let mut out: Vec<(BotId, u64)> = self.eliminated.clone();
+        out.sort_by_key(|&(id, tick)| (std::cmp::Reverse(tick), id));
+        alive
+            .into_iter()
+            .map(|(_, id)| id)
+            .chain(out.into_iter().map(|(id, _)| id))
+            .collect()
+    }
+
+    /// Get the number of remaining agents, including those waiting to respawn.
     pub fn remaining_agents(&self) -> usize {
         if let Ok(grid) = self.grid.read() {
-            grid.agents().len()
+            grid.agents().len() + self.respawns.len()
         } else {
             0
         }
     }
 }
 
+impl Drop for Engine {
+    /// Stop the bots, so an engine dropped mid-match, such as a cancelled
+    /// game, does not leave their threads waiting for events.
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use events::{events::BotDecision, queue::EventPriority};
     use std::sync::{
-        Arc,
         atomic::{AtomicBool, Ordering},
+        Arc,
     };
 
     #[tokio::test]
@@ -637,9 +1093,15 @@ mod tests {
             ..EngineConfig::default()
         };
         let (mut engine, mut rx, events) = Engine::new(cfg);
+        engine
+            .grid()
+            .write()
+            .unwrap()
+            .apply_delta(GridDelta::AddAgent(state::AgentState::new(1, (0, 0))));
         events.emit(
             Event::Bot(BotEvent::Decision {
                 bot_id: 1,
+                tick: 1,
                 decision: BotDecision::PlaceBomb,
             }),
             EventPriority::Normal,
@@ -651,6 +1113,683 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn placed_bombs_follow_rules_and_do_not_stack() {
+        use crate::config::{EngineConfig, GameRules};
+        use events::bus::EventFilter;
+        let cfg = EngineConfig {
+            width: 1,
+            height: 1,
+            rules: GameRules {
+                bomb_timer: 7,
+                ..GameRules::default()
+            },
+            ..EngineConfig::default()
+        };
+        let (mut engine, _rx, events) = Engine::new(cfg);
+        engine
+            .grid()
+            .write()
+            .unwrap()
+            .apply_delta(GridDelta::AddAgent(AgentState {
+                bombs_left: 2,
+                power: 3,
+                pierce: true,
+                ..AgentState::new(1, (0, 0))
+            }));
+        let filter = EventFilter::new(|e| matches!(e, Event::Game(GameEvent::BombPlaced { .. })));
+        let (_id, rx_event) = events.subscribe_with_filter(Some(filter));
+
+        for tick in 1..=2 {
+            events.emit(
+                Event::Bot(BotEvent::Decision {
+                    bot_id: 1,
+                    tick,
+                    decision: BotDecision::PlaceBomb,
+                }),
+                EventPriority::Normal,
+            );
+            engine.tick().await.unwrap();
+        }
+
+        let grid = engine.grid();
+        let grid = grid.read().unwrap();
+        assert_eq!(grid.bombs().len(), 1);
+        let bomb = &grid.bombs()[0];
+        assert_eq!((bomb.timer, bomb.power, bomb.pierce), (7, 3, true));
+        assert_eq!(grid.agents()[0].bombs_left, 1);
+        assert_eq!(
+            rx_event.try_recv().unwrap(),
+            Event::Game(GameEvent::BombPlaced {
+                entity_id: 1,
+                bomb_id: state::BombId(0),
+                position: (0, 0),
+                power: 3,
+            })
+        );
+        assert!(rx_event.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn engine_reports_resolved_moves() {
+        use crate::config::EngineConfig;
+        use events::bus::EventFilter;
+        use events::events::MoveOutcome;
+        let cfg = EngineConfig {
+            width: 3,
+            height: 1,
+            ..EngineConfig::default()
+        };
+        let (mut engine, _rx, events) = Engine::new(cfg);
+        {
+            let grid = engine.grid();
+            let mut grid = grid.write().unwrap();
+            for x in 0..3 {
+                grid.apply_delta(GridDelta::SetTile {
+                    x,
+                    y: 0,
+                    tile: state::Tile::Empty,
+                });
+            }
+            grid.apply_delta(GridDelta::AddAgent(state::AgentState::new(1, (0, 0))));
+            grid.apply_delta(GridDelta::AddAgent(state::AgentState::new(2, (2, 0))));
+        }
+        let filter = EventFilter::new(|e| matches!(e, Event::Game(GameEvent::MoveResolved { .. })));
+        let (_id, rx_event) = events.subscribe_with_filter(Some(filter));
+        for (bot_id, direction) in [(1, Direction::Right), (2, Direction::Left)] {
+            events.emit(
+                Event::Bot(BotEvent::Decision {
+                    bot_id,
+                    tick: 1,
+                    decision: BotDecision::Move(direction),
+                }),
+                EventPriority::Normal,
+            );
+        }
+        engine.tick().await.unwrap();
+        for (entity_id, from) in [(1, (0, 0)), (2, (2, 0))] {
+            assert_eq!(
+                rx_event.try_recv().unwrap(),
+                Event::Game(GameEvent::MoveResolved {
+                    entity_id,
+                    from,
+                    intended: Some((1, 0)),
+                    to: from,
+                    outcome: MoveOutcome::Yielded,
+                })
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn decisions_for_other_ticks_are_dropped() {
+        use crate::config::EngineConfig;
+        let cfg = EngineConfig {
+            width: 1,
+            height: 1,
+            ..EngineConfig::default()
+        };
+        let (mut engine, _rx, events) = Engine::new(cfg);
+        engine
+            .grid()
+            .write()
+            .unwrap()
+            .apply_delta(GridDelta::AddAgent(state::AgentState::new(1, (0, 0))));
+        events.emit(
+            Event::Bot(BotEvent::Decision {
+                bot_id: 1,
+                tick: 5,
+                decision: BotDecision::PlaceBomb,
+            }),
+            EventPriority::Normal,
+        );
+        engine.tick().await.unwrap();
+        assert!(engine.grid().read().unwrap().bombs().is_empty());
+    }
+
+    #[tokio::test]
+    async fn tick_requests_decisions_from_spawned_bots() {
+        use crate::config::EngineConfig;
+        use events::bus::EventFilter;
+        let (mut engine, _rx, events) = Engine::new(EngineConfig::default());
+        let filter = EventFilter::new(|e| {
+            matches!(
+                e,
+                Event::Game(GameEvent::TickRequested { .. } | GameEvent::TickCompleted { .. })
+            )
+        });
+        let (_id, rx_event) = events.subscribe_with_filter(Some(filter));
+        engine
+            .spawn_bot(BotConfig::new("b", ::bot::AiType::Reactive))
+            .unwrap();
+        engine.tick().await.unwrap();
+        assert_eq!(
+            rx_event.try_recv().unwrap(),
+            Event::Game(GameEvent::TickRequested { tick: 1 })
+        );
+        assert_eq!(
+            rx_event.try_recv().unwrap(),
+            Event::Game(GameEvent::TickCompleted { tick: 1 })
+        );
+        assert_eq!(engine.current_tick(), 1);
+    }
+
+    #[tokio::test]
+    async fn agents_collect_powerups_they_walk_over() {
+        use crate::config::EngineConfig;
+        use events::bus::EventFilter;
+        use state::PowerUpType;
+        let grid = GameGrid::from_ascii("######\n#1sf.#\n######").unwrap();
+        let cfg = EngineConfig {
+            width: 6,
+            height: 3,
+            ..EngineConfig::default()
+        };
+        let events = Arc::new(EventBus::new());
+        let (mut engine, _rx) =
+            Engine::with_components(cfg, Arc::new(RwLock::new(grid)), Arc::clone(&events));
+        engine
+            .grid()
+            .write()
+            .unwrap()
+            .apply_delta(GridDelta::AddAgent(state::AgentState::new(1, (1, 1))));
+        let filter = EventFilter::new(|e| matches!(e, Event::Bomb(_)));
+        let (_id, rx_event) = events.subscribe_with_filter(Some(filter));
+
+        for tick in 1..=2 {
+            events.emit(
+                Event::Bot(BotEvent::Decision {
+                    bot_id: 1,
+                    tick,
+                    decision: BotDecision::Move(Direction::Right),
+                }),
+                EventPriority::Normal,
+            );
+            engine.tick().await.unwrap();
+        }
+
+        // The speed power-up from the first tick carries the agent two tiles
+        // in the second, over the blast radius power-up.
+        let grid = engine.grid();
+        let grid = grid.read().unwrap();
+        let agent = &grid.agents()[0];
+        assert_eq!(agent.position, (4, 1));
+        assert_eq!((agent.speed, agent.power), (2, 2));
+        assert_eq!(grid.tile(2, 1), Some(Tile::Empty));
+        assert_eq!(grid.tile(3, 1), Some(Tile::Empty));
+        for power_type in [PowerUpType::Speed, PowerUpType::BlastRadius] {
+            assert_eq!(
+                rx_event.try_recv().unwrap(),
+                Event::bomb(BombEvent::PowerUpCollected {
+                    agent_id: 1,
+                    power_type,
+                })
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn bomb_count_pickups_count_live_bombs() {
+        use crate::config::{EngineConfig, GameRules, PowerUpRules};
+        let grid = GameGrid::from_ascii("#####\n#1b.#\n#####").unwrap();
+        let cfg = EngineConfig {
+            width: 5,
+            height: 3,
+            rules: GameRules {
+                powerups: PowerUpRules {
+                    max_bombs: 2,
+                    ..PowerUpRules::default()
+                },
+                ..GameRules::default()
+            },
+            ..EngineConfig::default()
+        };
+        let events = Arc::new(EventBus::new());
+        let (mut engine, _rx) =
+            Engine::with_components(cfg, Arc::new(RwLock::new(grid)), Arc::clone(&events));
+        {
+            let grid = engine.grid();
+            let mut grid = grid.write().unwrap();
+            grid.apply_delta(GridDelta::AddAgent(state::AgentState::new(1, (1, 1))));
+            grid.apply_delta(GridDelta::AddBomb(Bomb::new(BombId(0), 1, (3, 1), 50, 1)));
+        }
+        events.emit(
+            Event::Bot(BotEvent::Decision {
+                bot_id: 1,
+                tick: 1,
+                decision: BotDecision::Move(Direction::Right),
+            }),
+            EventPriority::Normal,
+        );
+        engine.tick().await.unwrap();
+
+        // One bomb in stock and one on the board already make the limit.
+        let grid = engine.grid();
+        let grid = grid.read().unwrap();
+        let agent = &grid.agents()[0];
+        assert_eq!(agent.position, (2, 1));
+        assert_eq!(agent.bombs_left as usize + grid.bombs().len(), 2);
+        assert_eq!(grid.tile(2, 1), Some(Tile::Empty));
+    }
+
+    #[tokio::test]
+    async fn hit_agents_respawn_until_out_of_lives() {
+        use crate::config::{EngineConfig, GameRules};
+        use events::bus::EventFilter;
+        let mut grid = GameGrid::from_ascii("#####\n#1.2#\n#####").unwrap();
+        grid.set_tile(1, 1, Tile::Explosion);
+        let cfg = EngineConfig {
+            width: 5,
+            height: 3,
+            rules: GameRules {
+                respawn_delay: 1,
+                invulnerability_ticks: 2,
+                ..GameRules::default()
+            },
+            ..EngineConfig::default()
+        };
+        let events = Arc::new(EventBus::new());
+        let (mut engine, _rx) =
+            Engine::with_components(cfg, Arc::new(RwLock::new(grid)), Arc::clone(&events));
+        engine
+            .grid()
+            .write()
+            .unwrap()
+            .apply_delta(GridDelta::AddAgent(AgentState {
+                lives: 2,
+                ..AgentState::new(1, (1, 1))
+            }));
+        let filter = EventFilter::new(|e| {
+            matches!(
+                e,
+                Event::Game(
+                    GameEvent::AgentHit { .. }
+                        | GameEvent::AgentRespawned { .. }
+                        | GameEvent::AgentEliminated { .. }
+                )
+            )
+        });
+        let (_id, rx_event) = events.subscribe_with_filter(Some(filter));
+
+        engine.tick().await.unwrap();
+        assert_eq!(engine.remaining_agents(), 1);
+        assert!(engine.grid().read().unwrap().agents().is_empty());
+
+        // The agent's own spawn point is still burning, so it takes the other.
+        engine.tick().await.unwrap();
+        engine
+            .grid()
+            .write()
+            .unwrap()
+            .set_tile(3, 1, Tile::Explosion);
+        engine.tick().await.unwrap();
+        assert_eq!(engine.grid().read().unwrap().agents()[0].position, (3, 1));
+
+        engine.tick().await.unwrap();
+        assert_eq!(engine.remaining_agents(), 0);
+        let expected = [
+            GameEvent::AgentHit {
+                entity_id: 1,
+                position: (1, 1),
+                lives_left: 1,
+            },
+            GameEvent::AgentRespawned {
+                entity_id: 1,
+                position: (3, 1),
+            },
+            GameEvent::AgentHit {
+                entity_id: 1,
+                position: (3, 1),
+                lives_left: 0,
+            },
+            GameEvent::AgentEliminated {
+                entity_id: 1,
+                tick: 4,
+            },
+        ];
+        for event in expected {
+            assert_eq!(rx_event.try_recv().unwrap(), Event::Game(event));
+        }
+    }
+
+    #[tokio::test]
+    async fn agents_with_kick_send_bombs_sliding() {
+        use crate::{config::EngineConfig, systems::BombSystem};
+        let grid = GameGrid::from_ascii("#######\n#.....#\n#######").unwrap();
+        let cfg = EngineConfig {
+            width: 7,
+            height: 3,
+            ..EngineConfig::default()
+        };
+        let events = Arc::new(EventBus::new());
+        let (mut engine, _rx) =
+            Engine::with_components(cfg, Arc::new(RwLock::new(grid)), Arc::clone(&events));
+        engine.add_system(Box::new(BombSystem::new()));
+        {
+            let grid = engine.grid();
+            let mut grid = grid.write().unwrap();
+            grid.apply_delta(GridDelta::AddAgent(AgentState {
+                kick: true,
+                ..AgentState::new(1, (1, 1))
+            }));
+            grid.apply_delta(GridDelta::AddBomb(Bomb::new(
+                state::BombId(0),
+                2,
+                (2, 1),
+                9,
+                1,
+            )));
+        }
+        let (_id, rx_event) = events.subscribe();
+
+        events.emit(
+            Event::Bot(BotEvent::Decision {
+                bot_id: 1,
+                tick: 1,
+                decision: BotDecision::Move(Direction::Right),
+            }),
+            EventPriority::Normal,
+        );
+        engine.tick().await.unwrap();
+        assert!(rx_event.try_iter().any(|e| e
+            == Event::bomb(BombEvent::Kicked {
+                bomb_id: state::BombId(0),
+                agent_id: 1,
+                direction: Direction::Right,
+            })));
+        engine.tick().await.unwrap();
+
+        let grid = engine.grid();
+        let grid = grid.read().unwrap();
+        assert_eq!(grid.agents()[0].position, (1, 1));
+        assert_eq!(grid.bombs()[0].position, (3, 1));
+        assert_eq!(grid.bombs()[0].sliding, Some(Direction::Right));
+    }
+
+    #[tokio::test]
+    async fn only_owners_detonate_remote_bombs() {
+        use crate::{config::EngineConfig, systems::BombSystem};
+        let grid = GameGrid::from_ascii("#####\n#...#\n#####").unwrap();
+        let cfg = EngineConfig {
+            width: 5,
+            height: 3,
+            ..EngineConfig::default()
+        };
+        let events = Arc::new(EventBus::new());
+        let (mut engine, _rx) =
+            Engine::with_components(cfg, Arc::new(RwLock::new(grid)), Arc::clone(&events));
+        engine.add_system(Box::new(BombSystem::new()));
+        {
+            let grid = engine.grid();
+            let mut grid = grid.write().unwrap();
+            grid.apply_delta(GridDelta::AddAgent(AgentState {
+                remote: true,
+                ..AgentState::new(1, (1, 1))
+            }));
+            grid.apply_delta(GridDelta::AddAgent(AgentState::new(2, (3, 1))));
+        }
+        let (_id, rx_event) = events.subscribe();
+        let decide = |tick, bot_id, decision| {
+            events.emit(
+                Event::Bot(BotEvent::Decision {
+                    bot_id,
+                    tick,
+                    decision,
+                }),
+                EventPriority::Normal,
+            );
+        };
+
+        decide(1, 1, BotDecision::PlaceBomb);
+        engine.tick().await.unwrap();
+        decide(2, 1, BotDecision::Move(Direction::Right));
+        for _ in 0..5 {
+            engine.tick().await.unwrap();
+        }
+        assert_eq!(engine.grid().read().unwrap().bombs().len(), 1);
+
+        decide(7, 2, BotDecision::Detonate(Some(BombId(0))));
+        engine.tick().await.unwrap();
+        decide(8, 1, BotDecision::Detonate(None));
+        engine.tick().await.unwrap();
+        assert_eq!(engine.grid().read().unwrap().bombs()[0].timer, 0);
+        engine.tick().await.unwrap();
+        assert!(engine.grid().read().unwrap().bombs().is_empty());
+
+        let detonated: Vec<_> = rx_event
+            .try_iter()
+            .filter(|e| matches!(e, Event::Bomb(BombEvent::Detonated { .. })))
+            .collect();
+        assert_eq!(
+            detonated,
+            vec![Event::bomb(BombEvent::Detonated {
+                bomb_id: BombId(0),
+                agent_id: 1,
+            })]
+        );
+    }
+
+    #[tokio::test]
+    async fn sudden_death_collapses_the_arena_inward() {
+        use crate::config::SuddenDeathRules;
+        let grid = GameGrid::from_ascii("#####\n#...#\n#####").unwrap();
+        let mut cfg = EngineConfig {
+            width: 5,
+            height: 3,
+            ..EngineConfig::default()
+        };
+        cfg.rules.bomb_timer = 10;
+        cfg.rules.sudden_death = Some(SuddenDeathRules {
+            start_tick: 2,
+            collapse_interval: 2,
+            warning_ticks: 1,
+        });
+        let events = Arc::new(EventBus::new());
+        let (mut engine, _rx) =
+            Engine::with_components(cfg, Arc::new(RwLock::new(grid)), Arc::clone(&events));
+        {
+            let grid = engine.grid();
+            let mut grid = grid.write().unwrap();
+            grid.apply_delta(GridDelta::AddAgent(AgentState::new(1, (1, 1))));
+            grid.apply_delta(GridDelta::AddAgent(AgentState::new(2, (3, 1))));
+        }
+        let (_id, rx_event) = events.subscribe();
+        events.emit(
+            Event::Bot(BotEvent::Decision {
+                bot_id: 2,
+                tick: 1,
+                decision: BotDecision::PlaceBomb,
+            }),
+            EventPriority::Normal,
+        );
+
+        engine.tick().await.unwrap();
+        engine.tick().await.unwrap();
+        {
+            let grid = engine.grid();
+            let grid = grid.read().unwrap();
+            assert_eq!(grid.tile(1, 1), Some(Tile::Wall));
+            assert_eq!(grid.agents().len(), 1);
+        }
+        assert_eq!(engine.check_game_end(), Some(MatchOutcome::Winner(2)));
+
+        for _ in 0..4 {
+            engine.tick().await.unwrap();
+        }
+        let grid = engine.grid();
+        let grid = grid.read().unwrap();
+        assert!(grid.agents().is_empty() && grid.bombs().is_empty());
+        assert_eq!(grid.tile(3, 1), Some(Tile::Wall));
+        drop(grid);
+
+        let announced: Vec<_> = rx_event
+            .try_iter()
+            .filter_map(|e| match e {
+                Event::Game(e @ GameEvent::TileCollapsing { .. })
+                | Event::Game(e @ GameEvent::TileCollapsed { .. })
+                | Event::Game(e @ GameEvent::AgentEliminated { .. }) => Some(e),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            announced,
+            vec![
+                GameEvent::TileCollapsing {
+                    position: (1, 1),
+                    tick: 2
+                },
+                GameEvent::TileCollapsed {
+                    position: (1, 1),
+                    tick: 2
+                },
+                GameEvent::AgentEliminated {
+                    entity_id: 1,
+                    tick: 2
+                },
+                GameEvent::TileCollapsing {
+                    position: (2, 1),
+                    tick: 4
+                },
+                GameEvent::TileCollapsed {
+                    position: (2, 1),
+                    tick: 4
+                },
+                GameEvent::TileCollapsing {
+                    position: (3, 1),
+                    tick: 6
+                },
+                GameEvent::TileCollapsed {
+                    position: (3, 1),
+                    tick: 6
+                },
+                GameEvent::AgentEliminated {
+                    entity_id: 2,
+                    tick: 6
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn matches_end_in_draws_and_timeouts() {
+        use crate::systems::BombSystem;
+        let corridor = || {
+            Arc::new(RwLock::new(
+                GameGrid::from_ascii("#####\n#...#\n#####").unwrap(),
+            ))
+        };
+        let mut cfg = EngineConfig {
+            width: 5,
+            height: 3,
+            ..EngineConfig::default()
+        };
+        cfg.rules.bomb_timer = 1;
+        cfg.rules.limits.max_ticks = Some(3);
+
+        let events = Arc::new(EventBus::new());
+        let (mut engine, _rx) =
+            Engine::with_components(cfg.clone(), corridor(), Arc::clone(&events));
+        engine.add_system(Box::new(BombSystem::new()));
+        {
+            let grid = engine.grid();
+            let mut grid = grid.write().unwrap();
+            grid.apply_delta(GridDelta::AddAgent(AgentState {
+                power: 2,
+                ..AgentState::new(1, (1, 1))
+            }));
+            grid.apply_delta(GridDelta::AddAgent(AgentState::new(2, (3, 1))));
+        }
+        events.emit(
+            Event::Bot(BotEvent::Decision {
+                bot_id: 1,
+                tick: 1,
+                decision: BotDecision::PlaceBomb,
+            }),
+            EventPriority::Normal,
+        );
+        engine.tick().await.unwrap();
+        assert_eq!(engine.check_game_end(), None);
+        engine.tick().await.unwrap();
+        assert_eq!(
+            engine.check_game_end(),
+            Some(MatchOutcome::Draw(vec![1, 2]))
+        );
+
+        let (mut engine, _rx) = Engine::with_components(cfg, corridor(), Arc::new(EventBus::new()));
+        {
+            let grid = engine.grid();
+            let mut grid = grid.write().unwrap();
+            grid.apply_delta(GridDelta::AddAgent(AgentState::new(1, (1, 1))));
+            grid.apply_delta(GridDelta::AddAgent(AgentState {
+                lives: 2,
+                ..AgentState::new(2, (3, 1))
+            }));
+        }
+        for _ in 0..2 {
+            engine.tick().await.unwrap();
+            assert_eq!(engine.check_game_end(), None);
+        }
+        engine.tick().await.unwrap();
+        assert_eq!(
+            engine.check_game_end(),
+            Some(MatchOutcome::Timeout {
+                ranking: vec![2, 1]
+            })
+        );
+    }
+
+    #[test]
+    fn bots_take_free_spawn_points_in_order() {
+        use crate::config::EngineConfig;
+        let grid = GameGrid::from_ascii("#####\n#1.2#\n#####").unwrap();
+        let cfg = EngineConfig {
+            width: 5,
+            height: 3,
+            ..EngineConfig::default()
+        };
+        let (mut engine, _rx) =
+            Engine::with_components(cfg, Arc::new(RwLock::new(grid)), Arc::new(EventBus::new()));
+        for _ in 0..2 {
+            engine
+                .spawn_bot(BotConfig::new("b", ::bot::AiType::Reactive))
+                .unwrap();
+        }
+        let positions: Vec<_> = engine
+            .grid()
+            .read()
+            .unwrap()
+            .agents()
+            .iter()
+            .map(|a| a.position)
+            .collect();
+        assert_eq!(positions, vec![(1, 1), (3, 1)]);
+        assert!(matches!(
+            engine.spawn_bot(BotConfig::new("c", ::bot::AiType::Reactive)),
+            Err(BotError::NoSpawnPoint(2))
+        ));
+    }
+
+    #[test]
+    fn spawn_order_is_shuffled_by_seed() {
+        use crate::config::EngineConfig;
+        let grid = GameGrid::new(15, 13);
+        let order = |seed| {
+            let cfg = EngineConfig {
+                seed,
+                shuffle_spawns: true,
+                ..EngineConfig::default()
+            };
+            spawn_order(&cfg, &grid)
+        };
+        assert_eq!(order(5), order(5));
+        assert_ne!(order(5), order(6));
+        let mut sorted = order(5);
+        sorted.sort();
+        let mut expected = grid.spawn_points().to_vec();
+        expected.sort();
+        assert_eq!(sorted, expected);
+    }
+
     #[tokio::test]
     async fn bomb_system_emits_event() {
         use crate::{config::EngineConfig, systems::BombSystem};
@@ -1,8 +1,12 @@
 pub mod game_engine;
+pub mod move_resolution;
+pub mod outcome;
 pub mod scheduler;
+pub mod sudden_death;
 
 #[cfg(test)]
 mod movement_test;
 
 pub use game_engine::Engine;
+pub use outcome::MatchOutcome;
 pub use scheduler::TaskScheduler;
@@ -0,0 +1,491 @@
+//! Simultaneous move resolution.
+//!
+//! All moves decided for a tick are settled together against the grid as it
+//! stood when the tick started, so the outcome never depends on the order in
+//! which bots answered.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use common::Direction;
+use events::events::{MoveOutcome, bot_events::BotId};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use state::{GameGrid, Tile};
+
+use crate::config::{MoveConflictRule, MovementRules};
+
+/// Result of resolving one agent's intended move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolvedMove {
+    /// Agent that requested the move.
+    pub bot_id: BotId,
+    /// Direction the agent wanted to move in.
+    pub direction: Direction,
+    /// Position at the start of the tick.
+    pub from: (u16, u16),
+    /// Tile the agent tried to enter, if it lies on the grid.
+    pub intended: Option<(u16, u16)>,
+    /// Position after resolution.
+    pub to: (u16, u16),
+    /// How the move was settled.
+    pub outcome: MoveOutcome,
+}
+
+/// Position one tile from `pos` in `direction`, if it lies on the grid.
+pub fn step(grid: &GameGrid, pos: (u16, u16), direction: Direction) -> Option<(u16, u16)> {
+    let (x, y) = pos;
+    let target = match direction {
+        Direction::Up => (x, y.checked_sub(1)?),
+        Direction::Down => (x, y.checked_add(1)?),
+        Direction::Left => (x.checked_sub(1)?, y),
+        Direction::Right => (x.checked_add(1)?, y),
+    };
+    if (target.0 as usize) < grid.width() && (target.1 as usize) < grid.height() {
+        Some(target)
+    } else {
+        None
+    }
+}
+
+/// Whether the tile at `pos` can be entered, ignoring agents.
+///
+/// Tiles holding a bomb cannot be entered.
+pub fn is_tile_walkable(grid: &GameGrid, pos: (u16, u16)) -> bool {
+    matches!(
+        grid.tile(pos.0 as usize, pos.1 as usize),
+        Some(Tile::Empty | Tile::PowerUp(_))
+    ) && grid.bombs().iter().all(|b| b.position != pos)
+}
+
+fn opposite(direction: Direction) -> Direction {
+    match direction {
+        Direction::Up => Direction::Down,
+        Direction::Down => Direction::Up,
+        Direction::Left => Direction::Right,
+        Direction::Right => Direction::Left,
+    }
+}
+
+/// Resolve every intended move of a tick at once.
+///
+/// Moves are settled in the following order:
+/// 1. moves off the grid or into walls, crates, bombs or explosions are blocked;
+/// 2. two agents trading places yield unless `rules.allow_swaps` is set;
+/// 3. agents contesting the same tile are settled by `rules.conflict`;
+/// 4. moves into tiles whose occupant stays put are blocked, repeated until
+///    nothing changes, so agents may follow each other or rotate in a cycle;
+/// 5. bouncers step back unless the tile behind them is taken, again repeated
+///    until nothing changes so no two agents ever share a tile.
+///
+/// `seed` and `tick` drive [`MoveConflictRule::PriorityBySeed`], making the
+/// winner reproducible for a given match. Results are ordered by bot id;
+/// intents of bots without an agent on the grid are ignored.
+pub fn resolve_moves(
+    grid: &GameGrid,
+    intents: &BTreeMap<BotId, Direction>,
+    rules: &MovementRules,
+    seed: u64,
+    tick: u64,
+) -> Vec<ResolvedMove> {
+    let positions: BTreeMap<BotId, (u16, u16)> =
+        grid.agents().iter().map(|a| (a.id, a.position)).collect();
+
+    let mut moves: BTreeMap<BotId, ResolvedMove> = BTreeMap::new();
+    // Agents whose move is still expected to succeed, with their target tile.
+    let mut pending: BTreeMap<BotId, (u16, u16)> = BTreeMap::new();
+
+    for (&bot_id, &direction) in intents {
+        let Some(&from) = positions.get(&bot_id) else {
+            continue;
+        };
+        let intended = step(grid, from, direction);
+        let mut resolved = ResolvedMove {
+            bot_id,
+            direction,
+            from,
+            intended,
+            to: from,
+            outcome: MoveOutcome::Blocked,
+        };
+        match intended {
+            Some(target) if is_tile_walkable(grid, target) => {
+                resolved.outcome = MoveOutcome::Moved;
+                resolved.to = target;
+                pending.insert(bot_id, target);
+            }
+            _ => {}
+        }
+        moves.insert(bot_id, resolved);
+    }
+
+    let fail = |moves: &mut BTreeMap<BotId, ResolvedMove>,
+                pending: &mut BTreeMap<BotId, (u16, u16)>,
+                bot_id: BotId,
+                outcome: MoveOutcome| {
+        pending.remove(&bot_id);
+        if let Some(mv) = moves.get_mut(&bot_id) {
+            mv.to = mv.from;
+            mv.outcome = outcome;
+        }
+    };
+
+    if !rules.allow_swaps {
+        let swappers: Vec<BotId> = pending
+            .iter()
+            .filter(|&(id, target)| {
+                pending.iter().any(|(other, other_target)| {
+                    other != id && positions[other] == *target && *other_target == positions[id]
+                })
+            })
+            .map(|(&id, _)| id)
+            .collect();
+        for id in swappers {
+            fail(&mut moves, &mut pending, id, MoveOutcome::Yielded);
+        }
+    }
+
+    let mut contested: BTreeMap<(u16, u16), Vec<BotId>> = BTreeMap::new();
+    for (&id, &target) in &pending {
+        contested.entry(target).or_default().push(id);
+    }
+    contested.retain(|_, ids| ids.len() > 1);
+
+    let mut rng = StdRng::seed_from_u64(seed ^ tick);
+    let mut bouncers = Vec::new();
+    for ids in contested.values() {
+        let winner = match rules.conflict {
+            MoveConflictRule::BothBlocked | MoveConflictRule::BothBounce => None,
+            MoveConflictRule::PriorityById => ids.iter().min().copied(),
+            MoveConflictRule::PriorityBySeed => Some(ids[rng.gen_range(0..ids.len())]),
+        };
+        for &id in ids {
+            if Some(id) == winner {
+                continue;
+            }
+            fail(&mut moves, &mut pending, id, MoveOutcome::Yielded);
+            if rules.conflict == MoveConflictRule::BothBounce {
+                bouncers.push(id);
+            }
+        }
+    }
+
+    loop {
+        let blocked: Vec<BotId> = pending
+            .iter()
+            .filter(|&(&id, target)| {
+                positions.iter().any(|(other, pos)| {
+                    *other != id && pos == target && !pending.contains_key(other)
+                })
+            })
+            .map(|(&id, _)| id)
+            .collect();
+        if blocked.is_empty() {
+            break;
+        }
+        for id in blocked {
+            fail(&mut moves, &mut pending, id, MoveOutcome::Blocked);
+        }
+    }
+
+    if !bouncers.is_empty() {
+        // Every agent not bouncing has a final position by now.
+        let occupied: BTreeSet<(u16, u16)> = positions
+            .iter()
+            .filter(|(id, _)| !bouncers.contains(id))
+            .map(|(id, pos)| moves.get(id).map_or(*pos, |mv| mv.to))
+            .collect();
+        let backs: BTreeMap<BotId, (u16, u16)> = bouncers
+            .iter()
+            .filter_map(|&id| {
+                let mv = &moves[&id];
+                let back = step(grid, mv.from, opposite(mv.direction))
+                    .filter(|&pos| is_tile_walkable(grid, pos))?;
+                Some((id, back))
+            })
+            .collect();
+        // Bouncers that may still step back; the others keep their tile.
+        let mut stepping: BTreeMap<BotId, (u16, u16)> = backs
+            .iter()
+            .filter(|&(&id, back)| {
+                !occupied.contains(back)
+                    && !backs
+                        .iter()
+                        .any(|(&other, other_back)| other != id && other_back == back)
+            })
+            .map(|(&id, &back)| (id, back))
+            .collect();
+        if !rules.allow_swaps {
+            stepping.retain(|id, back| {
+                !backs.iter().any(|(other, other_back)| {
+                    other != id && moves[other].from == *back && *other_back == moves[id].from
+                })
+            });
+        }
+        loop {
+            let blocked: Vec<BotId> = stepping
+                .iter()
+                .filter(|&(&id, back)| {
+                    bouncers.iter().any(|other| {
+                        *other != id && moves[other].from == *back && !stepping.contains_key(other)
+                    })
+                })
+                .map(|(&id, _)| id)
+                .collect();
+            if blocked.is_empty() {
+                break;
+            }
+            for id in blocked {
+                stepping.remove(&id);
+            }
+        }
+        for &id in &bouncers {
+            let mv = moves.get_mut(&id).expect("bouncer has a move");
+            mv.outcome = MoveOutcome::Bounced;
+            if let Some(&back) = stepping.get(&id) {
+                mv.to = back;
+            }
+        }
+    }
+
+    moves.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use state::components::AgentState;
+
+    /// Open `width` x `height` grid with agents at the given positions.
+    fn open_grid(width: usize, height: usize, agents: &[(BotId, (u16, u16))]) -> GameGrid {
+        let mut grid = GameGrid::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                grid.set_tile(x, y, Tile::Empty);
+            }
+        }
+        for &(id, pos) in agents {
+            grid.add_agent(AgentState::new(id, pos));
+        }
+        grid
+    }
+
+    fn rules(conflict: MoveConflictRule, allow_swaps: bool) -> MovementRules {
+        MovementRules {
+            conflict,
+            allow_swaps,
+        }
+    }
+
+    fn outcomes(moves: &[ResolvedMove]) -> Vec<(MoveOutcome, (u16, u16))> {
+        moves.iter().map(|m| (m.outcome, m.to)).collect()
+    }
+
+    fn contested() -> (GameGrid, BTreeMap<BotId, Direction>) {
+        let grid = open_grid(5, 3, &[(1, (1, 1)), (2, (3, 1))]);
+        let intents = BTreeMap::from([(1, Direction::Right), (2, Direction::Left)]);
+        (grid, intents)
+    }
+
+    #[test]
+    fn contested_tile_blocks_both() {
+        let (grid, intents) = contested();
+        let moves = resolve_moves(
+            &grid,
+            &intents,
+            &rules(MoveConflictRule::BothBlocked, false),
+            0,
+            0,
+        );
+        assert_eq!(
+            outcomes(&moves),
+            vec![
+                (MoveOutcome::Yielded, (1, 1)),
+                (MoveOutcome::Yielded, (3, 1))
+            ]
+        );
+    }
+
+    #[test]
+    fn contested_tile_bounces_both() {
+        let (grid, intents) = contested();
+        let moves = resolve_moves(
+            &grid,
+            &intents,
+            &rules(MoveConflictRule::BothBounce, false),
+            0,
+            0,
+        );
+        assert_eq!(
+            outcomes(&moves),
+            vec![
+                (MoveOutcome::Bounced, (0, 1)),
+                (MoveOutcome::Bounced, (4, 1))
+            ]
+        );
+    }
+
+    #[test]
+    fn bounce_into_wall_stays_put() {
+        let (mut grid, intents) = contested();
+        grid.set_tile(0, 1, Tile::Wall);
+        let moves = resolve_moves(
+            &grid,
+            &intents,
+            &rules(MoveConflictRule::BothBounce, false),
+            0,
+            0,
+        );
+        assert_eq!(
+            outcomes(&moves),
+            vec![
+                (MoveOutcome::Bounced, (1, 1)),
+                (MoveOutcome::Bounced, (4, 1))
+            ]
+        );
+    }
+
+    #[test]
+    fn bouncer_never_lands_on_a_staying_bouncer() {
+        let mut grid = open_grid(4, 4, &[(1, (0, 2)), (5, (1, 3)), (2, (0, 1)), (6, (2, 1))]);
+        grid.set_tile(0, 0, Tile::Wall);
+        let intents = BTreeMap::from([
+            (1, Direction::Down),
+            (5, Direction::Left),
+            (2, Direction::Right),
+            (6, Direction::Left),
+        ]);
+        let moves = resolve_moves(
+            &grid,
+            &intents,
+            &rules(MoveConflictRule::BothBounce, false),
+            0,
+            0,
+        );
+        assert_eq!(
+            outcomes(&moves),
+            vec![
+                (MoveOutcome::Bounced, (0, 2)),
+                (MoveOutcome::Bounced, (0, 1)),
+                (MoveOutcome::Bounced, (2, 3)),
+                (MoveOutcome::Bounced, (3, 1))
+            ]
+        );
+        let finals: BTreeSet<_> = moves.iter().map(|m| m.to).collect();
+        assert_eq!(finals.len(), moves.len());
+    }
+
+    #[test]
+    fn contested_tile_goes_to_lowest_id() {
+        let (grid, intents) = contested();
+        let moves = resolve_moves(
+            &grid,
+            &intents,
+            &rules(MoveConflictRule::PriorityById, false),
+            0,
+            0,
+        );
+        assert_eq!(
+            outcomes(&moves),
+            vec![(MoveOutcome::Moved, (2, 1)), (MoveOutcome::Yielded, (3, 1))]
+        );
+    }
+
+    #[test]
+    fn seeded_priority_is_reproducible() {
+        let (grid, intents) = contested();
+        let rules = rules(MoveConflictRule::PriorityBySeed, false);
+        let mut winners = BTreeSet::new();
+        for tick in 0..32 {
+            let first = resolve_moves(&grid, &intents, &rules, 42, tick);
+            assert_eq!(first, resolve_moves(&grid, &intents, &rules, 42, tick));
+            let moved: Vec<_> = first
+                .iter()
+                .filter(|m| m.outcome == MoveOutcome::Moved)
+                .map(|m| m.bot_id)
+                .collect();
+            assert_eq!(moved.len(), 1);
+            winners.insert(moved[0]);
+        }
+        assert_eq!(winners.len(), 2);
+    }
+
+    #[test]
+    fn swaps_follow_configuration() {
+        let grid = open_grid(4, 3, &[(1, (1, 1)), (2, (2, 1))]);
+        let intents = BTreeMap::from([(1, Direction::Right), (2, Direction::Left)]);
+
+        let moves = resolve_moves(
+            &grid,
+            &intents,
+            &rules(MoveConflictRule::BothBlocked, false),
+            0,
+            0,
+        );
+        assert_eq!(
+            outcomes(&moves),
+            vec![
+                (MoveOutcome::Yielded, (1, 1)),
+                (MoveOutcome::Yielded, (2, 1))
+            ]
+        );
+
+        let moves = resolve_moves(
+            &grid,
+            &intents,
+            &rules(MoveConflictRule::BothBlocked, true),
+            0,
+            0,
+        );
+        assert_eq!(
+            outcomes(&moves),
+            vec![(MoveOutcome::Moved, (2, 1)), (MoveOutcome::Moved, (1, 1))]
+        );
+    }
+
+    #[test]
+    fn agent_follows_into_vacated_tile() {
+        let grid = open_grid(5, 3, &[(1, (1, 1)), (2, (2, 1))]);
+        let intents = BTreeMap::from([(1, Direction::Right), (2, Direction::Right)]);
+        let moves = resolve_moves(&grid, &intents, &MovementRules::default(), 0, 0);
+        assert_eq!(
+            outcomes(&moves),
+            vec![(MoveOutcome::Moved, (2, 1)), (MoveOutcome::Moved, (3, 1))]
+        );
+    }
+
+    #[test]
+    fn blocked_leader_blocks_the_chain() {
+        let mut grid = open_grid(5, 3, &[(1, (1, 1)), (2, (2, 1))]);
+        grid.set_tile(3, 1, Tile::SoftCrate);
+        let intents = BTreeMap::from([(1, Direction::Right), (2, Direction::Right)]);
+        let moves = resolve_moves(&grid, &intents, &MovementRules::default(), 0, 0);
+        assert_eq!(
+            outcomes(&moves),
+            vec![
+                (MoveOutcome::Blocked, (1, 1)),
+                (MoveOutcome::Blocked, (2, 1))
+            ]
+        );
+    }
+
+    #[test]
+    fn rotation_moves_every_agent() {
+        let grid = open_grid(4, 4, &[(1, (1, 1)), (2, (2, 1)), (3, (2, 2)), (4, (1, 2))]);
+        let intents = BTreeMap::from([
+            (1, Direction::Right),
+            (2, Direction::Down),
+            (3, Direction::Left),
+            (4, Direction::Up),
+        ]);
+        let moves = resolve_moves(&grid, &intents, &MovementRules::default(), 0, 0);
+        assert!(moves.iter().all(|m| m.outcome == MoveOutcome::Moved));
+    }
+
+    #[test]
+    fn moves_off_grid_are_blocked() {
+        let grid = open_grid(3, 3, &[(1, (0, 0))]);
+        let intents = BTreeMap::from([(1, Direction::Up)]);
+        let moves = resolve_moves(&grid, &intents, &MovementRules::default(), 0, 0);
+        assert_eq!(moves[0].intended, None);
+        assert_eq!(moves[0].outcome, MoveOutcome::Blocked);
+    }
+}
@@ -1,10 +1,7 @@
 //! Regression test for bot movement position updates.
 
-use std::sync::{Arc, RwLock};
-use events::bus::EventBus;
 use events::events::{BotDecision, BotEvent, Event};
 use events::queue::EventPriority;
-use state::GameGrid;
 use super::Engine;
 use crate::config::EngineConfig;
 use common::Direction;
@@ -23,9 +20,6 @@ async fn test_bot_movement_updates_position() {
     let bot_config = bot::BotConfig::new("test_bot", bot::ai::AiType::Heuristic);
     let bot_id = engine.spawn_bot(bot_config).expect("Failed to spawn bot");
     
-    // Wait for cooldown to expire - bots get a cooldown when spawned
-    std::thread::sleep(std::time::Duration::from_millis(250));
-    
     // Get initial position
     let initial_position = {
         let grid = engine.grid();
@@ -40,6 +34,7 @@ async fn test_bot_movement_updates_position() {
     events.emit(
         Event::Bot(BotEvent::Decision {
             bot_id,
+            tick: engine.current_tick() + 1,
             decision: BotDecision::Move(Direction::Right),
         }),
         EventPriority::Normal,
@@ -101,6 +96,7 @@ async fn test_multiple_movements() {
         events.emit(
             Event::Bot(BotEvent::Decision {
                 bot_id,
+                tick: engine.current_tick() + 1,
                 decision: BotDecision::Move(direction),
             }),
             EventPriority::Normal,
@@ -0,0 +1,43 @@
+//! How a match ended.
+
+use events::events::bot_events::BotId;
+use serde::{Deserialize, Serialize};
+
+/// Result of a finished match.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchOutcome {
+    /// A single agent outlived every other one.
+    Winner(BotId),
+    /// The last agents standing went down on the same tick, in ascending id order.
+    Draw(Vec<BotId>),
+    /// A match limit ran out with several agents left.
+    Timeout {
+        /// Every agent that took part, best first.
+        ranking: Vec<BotId>,
+    },
+}
+
+impl MatchOutcome {
+    /// The agent that won outright, if any.
+    pub fn winner(&self) -> Option<BotId> {
+        match self {
+            MatchOutcome::Winner(id) => Some(*id),
+            MatchOutcome::Draw(_) | MatchOutcome::Timeout { .. } => None,
+        }
+    }
+
+    /// The same outcome with every bot id passed through `f`.
+    pub fn map_ids(self, f: impl Fn(BotId) -> BotId) -> Self {
+        match self {
+            MatchOutcome::Winner(id) => MatchOutcome::Winner(f(id)),
+            MatchOutcome::Draw(ids) => {
+                let mut ids: Vec<BotId> = ids.into_iter().map(f).collect();
+                ids.sort_unstable();
+                MatchOutcome::Draw(ids)
+            }
+            MatchOutcome::Timeout { ranking } => MatchOutcome::Timeout {
+                ranking: ranking.into_iter().map(f).collect(),
+            },
+        }
+    }
+}
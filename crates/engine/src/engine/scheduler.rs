@@ -143,7 +143,7 @@ mod tests {
         assert_eq!(order.lock().unwrap().as_slice(), &["A", "B", "C"]);
     }
 
-    #[tokio::test]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn runs_independent_tasks_in_parallel() {
         let mut scheduler = TaskScheduler::new();
         scheduler.add_task("A", vec![], true, || {
@@ -0,0 +1,150 @@
+//! Sudden death: the arena collapsing inward once a match runs long.
+//!
+//! The collapse order is fixed when the engine is built. Walls never
+//! disappear, so tiles that start out as walls are dropped from the spiral
+//! and every scheduled collapse is a tile that can still hold something.
+
+use state::{GameGrid, Tile};
+
+use crate::config::SuddenDeathRules;
+
+/// Tiles of a `width` x `height` grid in clockwise spiral order, starting at
+/// the top-left corner and winding towards the centre.
+pub fn spiral(width: usize, height: usize) -> Vec<(u16, u16)> {
+    let mut order = Vec::with_capacity(width * height);
+    let (mut left, mut top) = (0usize, 0usize);
+    let (mut right, mut bottom) = (width, height);
+    while left < right && top < bottom {
+        for x in left..right {
+            order.push((x as u16, top as u16));
+        }
+        for y in top + 1..bottom {
+            order.push(((right - 1) as u16, y as u16));
+        }
+        if bottom - top > 1 {
+            for x in (left..right - 1).rev() {
+                order.push((x as u16, (bottom - 1) as u16));
+            }
+        }
+        if right - left > 1 {
+            for y in (top + 1..bottom - 1).rev() {
+                order.push((left as u16, y as u16));
+            }
+        }
+        left += 1;
+        top += 1;
+        right -= 1;
+        bottom -= 1;
+    }
+    order
+}
+
+/// Ticks on which each tile of the arena is announced and collapses.
+#[derive(Debug, Clone)]
+pub struct CollapseSchedule {
+    /// Collapse tick and tile, in collapse order.
+    entries: Vec<(u64, (u16, u16))>,
+    warning_ticks: u64,
+    warned: usize,
+    collapsed: usize,
+}
+
+impl CollapseSchedule {
+    /// Schedule the collapse of every tile of `grid` that is not a wall.
+    pub fn new(grid: &GameGrid, rules: &SuddenDeathRules) -> Self {
+        let entries = spiral(grid.width(), grid.height())
+            .into_iter()
+            .filter(|&(x, y)| grid.tile(x as usize, y as usize) != Some(Tile::Wall))
+            .enumerate()
+            .map(|(i, pos)| {
+                (
+                    rules.start_tick + i as u64 * rules.collapse_interval as u64,
+                    pos,
+                )
+            })
+            .collect();
+        Self {
+            entries,
+            warning_ticks: rules.warning_ticks as u64,
+            warned: 0,
+            collapsed: 0,
+        }
+    }
+
+    /// Collapses to announce on `tick` that have not been announced yet.
+    pub fn warnings(&mut self, tick: u64) -> &[(u64, (u16, u16))] {
+        let start = self.warned;
+        while self
+            .entries
+            .get(self.warned)
+            .is_some_and(|&(at, _)| at.saturating_sub(self.warning_ticks) <= tick)
+        {
+            self.warned += 1;
+        }
+        &self.entries[start..self.warned]
+    }
+
+    /// Tiles collapsing on `tick` that have not collapsed yet.
+    pub fn collapses(&mut self, tick: u64) -> Vec<(u16, u16)> {
+        let start = self.collapsed;
+        while self
+            .entries
+            .get(self.collapsed)
+            .is_some_and(|&(at, _)| at <= tick)
+        {
+            self.collapsed += 1;
+        }
+        self.entries[start..self.collapsed]
+            .iter()
+            .map(|&(_, pos)| pos)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spiral_winds_inward_clockwise() {
+        assert_eq!(
+            spiral(3, 3),
+            vec![
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (2, 1),
+                (2, 2),
+                (1, 2),
+                (0, 2),
+                (0, 1),
+                (1, 1)
+            ]
+        );
+        for (w, h) in [(1, 4), (4, 1), (5, 2), (6, 7)] {
+            let mut order = spiral(w, h);
+            assert_eq!(order.len(), w * h);
+            order.sort();
+            order.dedup();
+            assert_eq!(order.len(), w * h);
+        }
+    }
+
+    #[test]
+    fn schedule_skips_walls_and_warns_ahead() {
+        let grid = GameGrid::from_ascii("#####\n#...#\n#####").unwrap();
+        let rules = SuddenDeathRules {
+            start_tick: 10,
+            collapse_interval: 3,
+            warning_ticks: 4,
+        };
+        let mut schedule = CollapseSchedule::new(&grid, &rules);
+        assert!(schedule.warnings(5).is_empty());
+        assert_eq!(schedule.warnings(9), &[(10, (1, 1)), (13, (2, 1))]);
+        assert!(schedule.collapses(9).is_empty());
+        assert_eq!(schedule.collapses(10), vec![(1, 1)]);
+        assert_eq!(schedule.warnings(20), &[(16, (3, 1))]);
+        assert_eq!(schedule.collapses(20), vec![(2, 1), (3, 1)]);
+        assert!(schedule.collapses(30).is_empty());
+    }
+}
@@ -12,16 +12,19 @@ pub mod tournament;
 use std::sync::{Arc, RwLock};
 
 use events::bus::EventBus;
+use log::{error, info};
 use state::GameGrid;
 
 pub use ::bot::BotConfig as BotRuntimeConfig;
+pub use bot::remote::{RemoteBotListener, RemoteConnection};
 pub use bot::{BotError, BotHandle, BotManager};
 pub use config::{
     AIConfig, BombConfig, ConfigError, EngineConfig, EventBusConfig, GameRules, LoggingConfig,
-    RLConfig, TournamentConfig, UnifiedBotConfig, UnifiedConfig,
+    MapConfig, MapFile, MatchLimits, MoveConflictRule, MovementRules, PowerUpRules, RLConfig,
+    TournamentConfig, UnifiedBotConfig, UnifiedConfig,
 };
 pub use engine::game_engine::EngineError;
-pub use engine::{Engine, TaskScheduler};
+pub use engine::{Engine, MatchOutcome, TaskScheduler};
 pub use simulation::{DeterminismChecker, Replay, ReplayRecorder};
 pub use systems::System;
 pub use tournament::TournamentManager;
@@ -91,6 +94,16 @@ impl SystemHandle {
     }
 }
 
+/// Register the systems every match runs: bombs and power-up drops.
+pub fn add_match_systems(engine: &mut Engine) {
+    let config = engine.config().clone();
+    engine.add_system(Box::new(systems::BombSystem::new()));
+    engine.add_system(Box::new(systems::PowerupSystem::new(
+        config.rules.powerups,
+        config.seed,
+    )));
+}
+
 /// Initializes the entire Bomberman system from a unified configuration.
 pub struct SystemInitializer {
     config: UnifiedConfig,
@@ -135,7 +148,7 @@ impl SystemInitializer {
     }
 
     async fn initialize_game_state(&mut self) -> Result<(), InitializationError> {
-        let grid = GameGrid::new(self.config.engine.width, self.config.engine.height);
+        let grid = self.config.engine.build_grid()?;
         self.game_grid = Some(Arc::new(RwLock::new(grid)));
         Ok(())
     }
@@ -145,10 +158,7 @@ impl SystemInitializer {
         let grid = Arc::clone(self.game_grid.as_ref().ok_or(InitializationError::Engine)?);
         let (mut engine, _rx) =
             engine::Engine::with_components(self.config.engine.clone(), grid, events);
-        
-        // Add the bomb system for bomb explosions
-        engine.add_system(Box::new(systems::BombSystem::new()));
-        
+        add_match_systems(&mut engine);
         self.engine = Some(engine);
         Ok(())
     }
@@ -159,28 +169,16 @@ impl SystemInitializer {
     }
 
     async fn initialize_bots(&mut self) -> Result<(), InitializationError> {
-        use ::bot::AiType;
         let engine = self.engine.as_mut().ok_or(InitializationError::Engine)?;
-        println!("🤖 Spawning {} bots...", self.config.bots.len());
+        info!("spawning {} bots", self.config.bots.len());
         for cfg in &self.config.bots {
-            let mut bot_cfg = ::bot::BotConfig::new(
-                &cfg.name,
-                match cfg.ai_type.to_lowercase().as_str() {
-                    "reactive" => AiType::Reactive,
-                    "planning" => AiType::Planning,
-                    _ => AiType::Heuristic,
-                },
-            );
-            bot_cfg.rl_mode = cfg.rl_mode;
-            bot_cfg.rl_model_path = cfg.rl_model_path.clone();
-            bot_cfg.decision_timeout = std::time::Duration::from_millis(cfg.decision_timeout_ms);
-            if let Err(e) = engine.spawn_bot(bot_cfg) {
-                println!("❌ Failed to spawn bot {}: {}", cfg.name, e);
+            if let Err(e) = engine.spawn_bot(cfg.runtime_config()) {
+                error!("failed to spawn bot {}: {}", cfg.name, e);
                 return Err(InitializationError::Bot(e.to_string()));
             }
             self.bot_count += 1;
         }
-        println!("✅ Successfully spawned {} bots", self.bot_count);
+        info!("spawned {} bots", self.bot_count);
         Ok(())
     }
 }
@@ -1,4 +1,6 @@
-use engine::{SystemInitializer, TournamentManager, UnifiedConfig, display::GameDisplay};
+use engine::{
+    display::GameDisplay, MatchOutcome, SystemInitializer, TournamentManager, UnifiedConfig,
+};
 use log::info;
 use std::time::Duration;
 use crossterm::event::{self, Event, KeyCode};
@@ -27,7 +29,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     if handle.has_tournament() {
         println!("🏆 Running tournament mode");
-        run_tournament(handle).await?;
+        run_tournament(handle, &config.bots).await?;
     } else {
         println!("🎯 Running interactive game mode");
         run_interactive_game(handle, config.engine.width, config.engine.height).await?;
@@ -57,12 +59,11 @@ async fn run_interactive_game(
     // Wait for user input to start
     wait_for_keypress().await?;
     
-    let game_running = true;
     let mut paused = false;
     let mut tick_count = 0;
     
     // Game loop
-    while game_running {
+    loop {
         // Handle input
         if event::poll(Duration::from_millis(100))? {
             if let Event::Key(key_event) = event::read()? {
@@ -97,20 +98,20 @@ async fn run_interactive_game(
             tokio::time::sleep(Duration::from_millis(200)).await;
             
             // Check for game end conditions
-            if let Some(winner) = engine.check_game_end() {
-                if winner == usize::MAX {
-                    println!("\n🏁 GAME ENDED - It's a tie! All players eliminated.");
-                } else {
-                    println!("\n🏆 GAME ENDED - Bot {} wins!", winner);
+            if let Some(outcome) = engine.check_game_end() {
+                match outcome {
+                    MatchOutcome::Winner(winner) => {
+                        println!("\n🏆 GAME ENDED - Bot {} wins!", winner);
+                    }
+                    MatchOutcome::Draw(bots) => {
+                        println!("\n🏁 GAME ENDED - It's a draw between bots {:?}!", bots);
+                    }
+                    MatchOutcome::Timeout { ranking } => {
+                        println!("\n⏱️ GAME ENDED - Time is up after {} ticks, ranking {:?}", tick_count, ranking);
+                    }
                 }
                 break;
             }
-            
-            // Stop after reasonable number of ticks for demo (if no winner)
-            if tick_count >= 100 {
-                info!("Demo completed after {} ticks", tick_count);
-                break;
-            }
         } else {
             // Still render when paused
             display.render(&grid)?;
@@ -126,6 +127,7 @@ async fn run_interactive_game(
 
 async fn run_tournament(
     system_handle: engine::SystemHandle,
+    bots: &[engine::config::UnifiedBotConfig],
 ) -> Result<(), Box<dyn std::error::Error>> {
     use std::time::Duration;
 
@@ -134,34 +136,61 @@ async fn run_tournament(
         .cloned()
         .ok_or("Tournament configuration not found")?;
 
-    let mut tournament_manager = TournamentManager::new(tournament_config.clone(), system_handle);
-
-    tournament_manager.start_registration().await?;
-    info!("Tournament registration opened");
+    let checkpoint = tournament_config
+        .checkpoint_file
+        .clone()
+        .filter(|path| path.exists());
+    let mut tournament_manager = if let Some(path) = checkpoint {
+        info!("Resuming tournament from {}", path.display());
+        TournamentManager::resume(&path, system_handle).await?
+    } else {
+        let mut manager = TournamentManager::new(tournament_config.clone(), system_handle);
+        manager.start_registration().await?;
+        info!("Tournament registration opened");
+        for bot in bots {
+            manager.register_bot(bot.clone()).await?;
+        }
 
-    tokio::time::sleep(Duration::from_secs(
-        tournament_config.registration_timeout_seconds,
-    ))
-    .await;
+        let window = Duration::from_secs(tournament_config.registration_timeout_seconds);
+        if tournament_config.allow_remote_bots {
+            if let Some(addr) = manager.remote_address() {
+                println!("🌐 Remote bots can connect to {}", addr);
+            }
+            let remote = manager.register_remote_bots(window).await?;
+            info!("{} remote bots registered", remote.len());
+        } else {
+            tokio::time::sleep(window).await;
+        }
 
-    tournament_manager.start_tournament().await?;
-    info!("Tournament started");
+        manager.start_tournament().await?;
+        info!("Tournament started");
+        manager
+    };
 
     while tournament_manager.has_next_round() {
         let results = tournament_manager.run_next_round().await?;
         info!("Completed round with {} games", results.len());
+        if let Some(bracket) = tournament_manager.bracket() {
+            println!("{}", bracket);
+        }
     }
 
     let final_results = tournament_manager.finalize_tournament().await?;
     info!("Tournament completed");
+    if let Some(path) = &final_results.archive {
+        info!("Results archived to {}", path.display());
+    }
     display_tournament_results(&final_results);
 
     Ok(())
 }
 
 fn display_tournament_results(results: &engine::tournament::TournamentResults) {
-    for (bot_id, score, rank) in &results.rankings {
-        info!("Bot {} rank {} with {} wins", bot_id, rank, score.wins);
+    for ranking in &results.rankings {
+        info!(
+            "Bot {} rank {} with {:.1} points ({} wins)",
+            ranking.bot, ranking.rank, ranking.points, ranking.score.wins
+        );
     }
 }
 
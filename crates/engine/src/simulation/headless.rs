@@ -0,0 +1,279 @@
+//! Batches of matches played without a display, as fast as the bots answer.
+//!
+//! Every match of a batch is built from the same [`UnifiedConfig`] through
+//! [`SystemInitializer`], with the match seed replacing `engine.seed`, and
+//! runs until [`Engine::check_game_end`](crate::Engine::check_game_end)
+//! reports an outcome.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{InitializationError, MatchOutcome, SystemInitializer, UnifiedConfig};
+
+/// Result of one headless match.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchRecord {
+    /// Position of the match within its batch.
+    pub index: usize,
+    /// Seed the match was played with.
+    pub seed: u64,
+    /// Ticks played.
+    pub ticks: u64,
+    /// Wall-clock time the match took, in milliseconds.
+    pub elapsed_ms: u64,
+    /// How the match ended.
+    pub outcome: MatchOutcome,
+    /// Bot names, indexed by bot id.
+    pub bots: Vec<String>,
+}
+
+impl MatchRecord {
+    /// Name of the bot with `id`.
+    pub fn bot_name(&self, id: usize) -> &str {
+        self.bots.get(id).map(String::as_str).unwrap_or("?")
+    }
+}
+
+/// Totals over a batch of matches.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BatchSummary {
+    /// Matches played.
+    pub matches: usize,
+    /// Outright wins per bot name.
+    pub wins: BTreeMap<String, usize>,
+    /// Matches ending in a draw.
+    pub draws: usize,
+    /// Matches stopped by a limit.
+    pub timeouts: usize,
+    /// Average number of ticks per match.
+    pub mean_ticks: f64,
+    /// Wall-clock time of the whole batch, in milliseconds.
+    pub elapsed_ms: u64,
+}
+
+impl BatchSummary {
+    /// Summarise `records`.
+    pub fn from_records(records: &[MatchRecord]) -> Self {
+        let mut summary = BatchSummary {
+            matches: records.len(),
+            ..BatchSummary::default()
+        };
+        for record in records {
+            for name in &record.bots {
+                summary.wins.entry(name.clone()).or_default();
+            }
+            match &record.outcome {
+                MatchOutcome::Winner(id) => {
+                    *summary
+                        .wins
+                        .entry(record.bot_name(*id).to_string())
+                        .or_default() += 1;
+                }
+                MatchOutcome::Draw(_) => summary.draws += 1,
+                MatchOutcome::Timeout { .. } => summary.timeouts += 1,
+            }
+            summary.elapsed_ms += record.elapsed_ms;
+        }
+        if !records.is_empty() {
+            let ticks: u64 = records.iter().map(|r| r.ticks).sum();
+            summary.mean_ticks = ticks as f64 / records.len() as f64;
+        }
+        summary
+    }
+}
+
+/// Plays batches of matches without rendering or pacing ticks.
+pub struct HeadlessRunner {
+    config: UnifiedConfig,
+}
+
+impl HeadlessRunner {
+    /// Create a runner for matches described by `config`.
+    ///
+    /// Matches are held to [`MatchLimits::bounded`](crate::MatchLimits::bounded)
+    /// so a batch always finishes.
+    pub fn new(mut config: UnifiedConfig) -> Self {
+        config.engine.rules.limits = config.engine.rules.limits.bounded();
+        Self { config }
+    }
+
+    /// Play `matches` matches, the `i`-th one with `seed + i`.
+    pub async fn run(
+        &self,
+        matches: usize,
+        seed: u64,
+    ) -> Result<Vec<MatchRecord>, InitializationError> {
+        let mut records = Vec::with_capacity(matches);
+        for index in 0..matches {
+            records.push(
+                self.run_match(index, seed.wrapping_add(index as u64))
+                    .await?,
+            );
+        }
+        Ok(records)
+    }
+
+    /// Play a single match with `seed`.
+    pub async fn run_match(
+        &self,
+        index: usize,
+        seed: u64,
+    ) -> Result<MatchRecord, InitializationError> {
+        let mut config = self.config.clone();
+        config.engine.seed = seed;
+        let bots = config.bots.iter().map(|b| b.name.clone()).collect();
+        let started = Instant::now();
+        let mut engine = SystemInitializer::new(config)
+            .initialize()
+            .await?
+            .into_engine();
+        let outcome = loop {
+            engine
+                .tick()
+                .await
+                .map_err(|e| InitializationError::Bot(e.to_string()))?;
+            if let Some(outcome) = engine.check_game_end() {
+                break outcome;
+            }
+        };
+        engine.shutdown();
+        Ok(MatchRecord {
+            index,
+            seed,
+            ticks: engine.current_tick(),
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            outcome,
+            bots,
+        })
+    }
+}
+
+/// Write `records` as a pretty-printed JSON array.
+pub fn write_json<P: AsRef<Path>>(path: P, records: &[MatchRecord]) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(records)?;
+    fs::write(path, json)
+}
+
+/// Write `records` as CSV, one match per row.
+///
+/// `result` is `winner`, `draw` or `timeout`. `ranking` lists bot names
+/// separated by `;`: the winner, the drawn bots, or the timeout ranking.
+pub fn write_csv<P: AsRef<Path>>(path: P, records: &[MatchRecord]) -> io::Result<()> {
+    let mut out = String::from("match,seed,ticks,elapsed_ms,result,ranking\n");
+    for record in records {
+        let (result, ids) = match &record.outcome {
+            MatchOutcome::Winner(id) => ("winner", vec![*id]),
+            MatchOutcome::Draw(ids) => ("draw", ids.clone()),
+            MatchOutcome::Timeout { ranking } => ("timeout", ranking.clone()),
+        };
+        let ranking: Vec<&str> = ids.iter().map(|&id| record.bot_name(id)).collect();
+        out.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            record.index,
+            record.seed,
+            record.ticks,
+            record.elapsed_ms,
+            result,
+            csv_field(&ranking.join(";"))
+        ));
+    }
+    fs::write(path, out)
+}
+
+/// Quote `field` if it holds a character CSV readers treat specially.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::*;
+
+    fn config(bots: usize) -> UnifiedConfig {
+        UnifiedConfig {
+            engine: EngineConfig::default(),
+            event_bus: EventBusConfig {
+                buffer_size: 1,
+                max_subscribers: 1,
+            },
+            bots: (0..bots)
+                .map(|i| UnifiedBotConfig {
+                    name: format!("b{}", i),
+                    ai_type: "heuristic".into(),
+                    rl_mode: false,
+                    rl_model_path: None,
+                    decision_timeout_ms: 50,
+                })
+                .collect(),
+            tournament: None,
+            ai: AIConfig::default(),
+            rl: None,
+            bombs: BombConfig::default(),
+            logging: LoggingConfig::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn batches_finish_and_are_written() {
+        let mut cfg = config(2);
+        cfg.engine.rules.limits.max_ticks = Some(5);
+        let runner = HeadlessRunner::new(cfg);
+        let records = runner.run(2, 7).await.unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!((records[0].seed, records[1].seed), (7, 8));
+        assert!(records.iter().all(|r| r.ticks <= 5));
+
+        let summary = BatchSummary::from_records(&records);
+        assert_eq!(summary.matches, 2);
+        let decided: usize =
+            summary.wins.values().sum::<usize>() + summary.draws + summary.timeouts;
+        assert_eq!(decided, 2);
+
+        let dir = tempfile::tempdir().unwrap();
+        write_json(dir.path().join("results.json"), &records).unwrap();
+        let json = fs::read_to_string(dir.path().join("results.json")).unwrap();
+        let back: Vec<MatchRecord> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, records);
+
+        write_csv(dir.path().join("results.csv"), &records).unwrap();
+        let csv = fs::read_to_string(dir.path().join("results.csv")).unwrap();
+        assert_eq!(csv.lines().count(), 3);
+        assert!(csv.lines().nth(1).unwrap().starts_with("0,7,"));
+    }
+
+    #[test]
+    fn summary_counts_outcomes_by_name() {
+        let record = |outcome| MatchRecord {
+            index: 0,
+            seed: 0,
+            ticks: 10,
+            elapsed_ms: 1,
+            outcome,
+            bots: vec!["a".into(), "b".into()],
+        };
+        let summary = BatchSummary::from_records(&[
+            record(MatchOutcome::Winner(1)),
+            record(MatchOutcome::Draw(vec![0, 1])),
+            record(MatchOutcome::Timeout {
+                ranking: vec![0, 1],
+            }),
+        ]);
+        assert_eq!(
+            summary.wins,
+            BTreeMap::from([("a".into(), 0), ("b".into(), 1)])
+        );
+        assert_eq!((summary.draws, summary.timeouts), (1, 1));
+        assert_eq!(summary.mean_ticks, 10.0);
+        assert_eq!(csv_field("x,y"), "\"x,y\"");
+    }
+}
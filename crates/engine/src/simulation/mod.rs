@@ -1,7 +1,9 @@
 pub mod determinism;
+pub mod headless;
 pub mod replay;
 
 pub use determinism::{DeterminismChecker, hash_grid};
+pub use headless::{BatchSummary, HeadlessRunner, MatchRecord};
 pub use replay::{Replay, ReplayRecorder};
 
 #[cfg(test)]
@@ -28,4 +30,37 @@ mod tests {
         engine2.load_replay(&replay);
         assert_eq!(engine2.determinism_hashes(), recorded_hashes.as_slice());
     }
+
+    #[tokio::test]
+    async fn replay_records_explosions() {
+        use crate::systems::BombSystem;
+        use state::grid::GridDelta;
+
+        let cfg = EngineConfig {
+            width: 5,
+            height: 3,
+            ..EngineConfig::default()
+        };
+        let (mut engine, _rx, _events) = Engine::new(cfg);
+        engine.add_system(Box::new(BombSystem::new()));
+        engine
+            .grid()
+            .write()
+            .unwrap()
+            .apply_delta(GridDelta::AddBomb(state::Bomb::new(
+                state::BombId(0),
+                0,
+                (1, 1),
+                1,
+                1,
+            )));
+        engine.start_replay_recording();
+        engine.tick().await.unwrap();
+        let replay = engine.stop_replay_recording();
+        assert!(
+            replay
+                .deltas()
+                .contains(&GridDelta::RemoveBomb(state::BombId(0)))
+        );
+    }
 }
@@ -3,10 +3,11 @@ use std::sync::{
     atomic::{AtomicBool, Ordering},
 };
 
+use serde::{Deserialize, Serialize};
 use state::{GameGrid, grid::GridDelta};
 
 /// Recorded sequence of [`GridDelta`] events.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Replay {
     deltas: Vec<GridDelta>,
 }
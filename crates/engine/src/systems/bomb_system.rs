@@ -1,7 +1,7 @@
 use std::sync::{Arc, RwLock};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashSet};
 
-use bombs::{BombManager, BombId, Bomb as BombsCrateBomb};
+use bombs::{ChainReactionHandler, ExplosionCalculator, kick_bomb};
 use events::{
     bus::EventBus,
     events::{BombEvent, Event},
@@ -13,23 +13,34 @@ use super::System;
 /// Manages bombs using logic from the `bombs` crate.
 /// This system acts as a bridge between the engine and the bombs crate,
 /// ensuring all bomb logic resides in the bombs crate as intended.
+///
+/// Bombs whose timers run out explode in a `+` shape and set off every
+/// other bomb their blasts reach in the same tick. Kicked bombs slide one
+/// tile per tick before timers advance. Crates destroyed by a chain are
+/// credited to the owner of the bomb that started it.
+///
+/// Every grid change is applied and broadcast as an [`Event::Grid`] as it
+/// happens, so bots and remote observers see bombs leave, flames spread and
+/// bombs return to their owners.
 pub struct BombSystem {
-    bomb_manager: BombManager,
-    explosion_timers: HashMap<(usize, usize), u8>, // Position -> ticks remaining
+    calculator: ExplosionCalculator,
+    chains: ChainReactionHandler,
+    explosion_timers: BTreeMap<(usize, usize), u8>, // Position -> ticks remaining
 }
 
 impl BombSystem {
     /// Create a new [`BombSystem`].
     pub fn new() -> Self {
         Self {
-            bomb_manager: BombManager::new(),
-            explosion_timers: HashMap::new(),
+            calculator: ExplosionCalculator::new(),
+            chains: ChainReactionHandler::new(),
+            explosion_timers: BTreeMap::new(),
         }
     }
 
     /// Updates the explosion timers for all currently exploding bombs.
     /// This is necessary to manage the animation of explosions.
-    fn update_explosion_timers(&mut self, grid: &Arc<RwLock<GameGrid>>) {
+    fn update_explosion_timers(&mut self, grid: &Arc<RwLock<GameGrid>>, events: &EventBus) {
         let mut grid_lock = grid.write().unwrap();
         let mut to_remove = Vec::new();
 
@@ -37,11 +48,12 @@ impl BombSystem {
             if *timer == 0 {
                 // This explosion has finished its animation
                 let delta = GridDelta::SetTile {
-                    x: pos.0 as usize,
-                    y: pos.1 as usize,
+                    x: pos.0,
+                    y: pos.1,
                     tile: Tile::Empty,
                 };
-                grid_lock.apply_delta(delta);
+                grid_lock.apply_delta(delta.clone());
+                events.broadcast(Event::Grid(delta));
                 to_remove.push(*pos);
             } else {
                 // Decrement timer for all explosions
@@ -54,6 +66,59 @@ impl BombSystem {
         }
         drop(grid_lock);
     }
+
+    /// Moves every sliding bomb one tile along its direction.
+    ///
+    /// A bomb stops in front of walls, crates, power-ups, agents and other
+    /// bombs. A bomb sliding into flames stops there and is set off.
+    fn slide_bombs(&mut self, grid: &Arc<RwLock<GameGrid>>, events: &EventBus) {
+        let mut grid_lock = grid.write().unwrap();
+        let mut sliding: Vec<_> = grid_lock
+            .bombs()
+            .iter()
+            .filter(|b| b.sliding.is_some())
+            .cloned()
+            .collect();
+        if sliding.is_empty() {
+            return;
+        }
+        sliding.sort_by_key(|b| b.id);
+
+        let size = (grid_lock.width() as u16, grid_lock.height() as u16);
+        let mut blocked: HashSet<(u16, u16)> = grid_lock
+            .agents()
+            .iter()
+            .map(|a| a.position)
+            .chain(grid_lock.bombs().iter().map(|b| b.position))
+            .collect();
+        for y in 0..grid_lock.height() {
+            for x in 0..grid_lock.width() {
+                if !matches!(grid_lock.tile(x, y), Some(Tile::Empty | Tile::Explosion)) {
+                    blocked.insert((x as u16, y as u16));
+                }
+            }
+        }
+
+        for mut bomb in sliding {
+            let direction = bomb.sliding.expect("only sliding bombs are collected");
+            let mut kicked = bombs::Bomb::from(&bomb);
+            if kick_bomb(&mut kicked, direction, size, &blocked) {
+                blocked.remove(&bomb.position);
+                blocked.insert(kicked.position);
+                bomb.position = kicked.position;
+                let (x, y) = (bomb.position.0 as usize, bomb.position.1 as usize);
+                if grid_lock.tile(x, y) == Some(Tile::Explosion) {
+                    bomb.sliding = None;
+                    bomb.timer = 0;
+                }
+            } else {
+                bomb.sliding = None;
+            }
+            let delta = GridDelta::UpdateBomb(bomb);
+            grid_lock.apply_delta(delta.clone());
+            events.broadcast(Event::Grid(delta));
+        }
+    }
 }
 
 impl System for BombSystem {
@@ -63,170 +128,80 @@ impl System for BombSystem {
 
     fn run(&mut self, grid: &Arc<RwLock<GameGrid>>, events: &EventBus) -> Option<GridDelta> {
         // First, update explosion timers and clear expired ones
-        self.update_explosion_timers(grid);
-        
-        // Listen for bomb placement events
-        // TODO: Implement proper event subscription mechanism
-        
-        // For now, sync grid bombs to bomb manager and process timers
-        let exploding_bombs = {
-            let mut grid_lock = grid.write().unwrap();
-            let mut exploding = Vec::new();
-            
-            // Tick all bombs and collect those that should explode
-            for bomb in grid_lock.bombs_mut() {
-                bomb.tick();
-                if bomb.is_exploding() {
-                    exploding.push(bomb.clone());
-                }
-            }
-            
-            // Don't remove exploding bombs yet - we need them for explosion calculation
-            exploding
-        };
-        
-        if exploding_bombs.is_empty() {
+        self.update_explosion_timers(grid, events);
+        self.slide_bombs(grid, events);
+
+        let mut grid_lock = grid.write().unwrap();
+        // Remote bombs only go off when detonated or caught in a blast
+        for bomb in grid_lock.bombs_mut().iter_mut().filter(|b| !b.remote) {
+            bomb.tick();
+        }
+        let explosions = self.calculator.calculate_explosions(&grid_lock);
+        if explosions.is_empty() {
             return None;
         }
-        
-        // Use bombs crate to calculate explosions
-        let grid_lock = grid.read().unwrap();
-        let grid_size = (grid_lock.width() as u16, grid_lock.height() as u16);
-        
-        // Build walls set for explosion calculation
-        let mut obstacles = std::collections::HashSet::new();
-        for y in 0..grid_lock.height() {
-            for x in 0..grid_lock.width() {
-                if let Some(tile) = grid_lock.tile(x, y) {
-                    use state::Tile;
-                    match tile {
-                        Tile::Wall | Tile::SoftCrate => {
-                            obstacles.insert((x as u16, y as u16));
-                        }
-                        _ => {}
-                    }
+        let reactions = self.chains.process_chain_reactions(explosions, &grid_lock);
+
+        for reaction in &reactions {
+            let trigger = reaction
+                .bombs
+                .first()
+                .and_then(|id| grid_lock.bombs().iter().find(|b| b.id == *id))
+                .map(|b| (b.id, b.owner));
+            for id in &reaction.bombs {
+                let Some(bomb) = grid_lock.bombs().iter().find(|b| b.id == *id).cloned() else {
+                    continue;
+                };
+                let delta = GridDelta::RemoveBomb(bomb.id);
+                grid_lock.apply_delta(delta.clone());
+                events.broadcast(Event::Grid(delta));
+                events.broadcast(Event::bomb(BombEvent::Exploded {
+                    bomb_id: bomb.id,
+                    position: bomb.position,
+                    radius: bomb.power as u32,
+                }));
+                // The owner gets the bomb back once it has gone off
+                if let Some(owner) = grid_lock.agents().iter().find(|a| a.id == bomb.owner) {
+                    let mut owner = owner.clone();
+                    owner.bombs_left = owner.bombs_left.saturating_add(1);
+                    let delta = GridDelta::UpdateAgent(owner);
+                    grid_lock.apply_delta(delta.clone());
+                    events.broadcast(Event::Grid(delta));
                 }
             }
-        }
-        drop(grid_lock);
-        
-        // Calculate explosions using the bombs crate
-        let mut all_affected_positions = Vec::new();
-        let mut bombs_to_remove = Vec::new();
-        
-        for bomb in &exploding_bombs {
-            // Convert state::Bomb to bombs crate format and add to manager for calculation
-            let bomb_id = BombId(bomb.owner as u32);
-            let bombs_crate_bomb = BombsCrateBomb::new(
-                bomb_id,
-                bomb.owner,
-                bomb.position,
-                0, // Timer is 0 since it's exploding
-                bomb.power,
-            );
-            self.bomb_manager.add_bomb(bombs_crate_bomb);
-            
-            // Calculate explosion using bombs crate
-            match self.bomb_manager.calculate_explosion(bomb_id, grid_size, &obstacles) {
-                Ok(explosion) => {
-                    // Broadcast explosion event
-                    events.broadcast(Event::bomb(BombEvent::Exploded {
-                        position: bomb.position,
-                        radius: bomb.power as u32,
-                    }));
-                    
-                    all_affected_positions.extend(explosion.affected_cells);
-                    bombs_to_remove.push(bomb.position);
-                }
-                Err(e) => {
-                    eprintln!("Explosion calculation failed: {:?}", e);
-                }
+            if reaction.bombs.len() > 1 {
+                events.broadcast(Event::bomb(BombEvent::ChainReaction {
+                    bombs: reaction.bombs.clone(),
+                    positions: reaction.positions.clone(),
+                }));
             }
-        }
-        
-        // Apply explosion effects to the grid
-        if !all_affected_positions.is_empty() {
-            let mut grid_lock = grid.write().unwrap();
-            
-            // Track which agents had bombs explode to restore their bomb count
-            let mut agents_to_restore_bombs = std::collections::HashSet::new();
-            
-            for pos in &all_affected_positions {
-                // Create explosion tile
+
+            let crates: Vec<_> = reaction
+                .positions
+                .iter()
+                .copied()
+                .filter(|p| grid_lock.tile(p.0 as usize, p.1 as usize) == Some(Tile::SoftCrate))
+                .collect();
+            if let (Some((bomb_id, agent_id)), false) = (trigger, crates.is_empty()) {
+                events.broadcast(Event::bomb(BombEvent::CratesDestroyed {
+                    bomb_id,
+                    agent_id,
+                    positions: crates,
+                }));
+            }
+
+            for pos in &reaction.positions {
                 let delta = GridDelta::SetTile {
                     x: pos.0 as usize,
                     y: pos.1 as usize,
                     tile: Tile::Explosion,
                 };
-                grid_lock.apply_delta(delta);
-                
+                grid_lock.apply_delta(delta.clone());
+                events.broadcast(Event::Grid(delta));
                 // Set explosion timer (3 ticks for animation)
                 self.explosion_timers.insert((pos.0 as usize, pos.1 as usize), 3);
-                
-                // Destroy soft crates
-                if let Some(state::Tile::SoftCrate) = grid_lock.tile(pos.0 as usize, pos.1 as usize) {
-                    let delta = GridDelta::SetTile {
-                        x: pos.0 as usize,
-                        y: pos.1 as usize,
-                        tile: state::Tile::Empty,
-                    };
-                    grid_lock.apply_delta(delta);
-                }
-                
-                // Remove agents hit by explosion
-                let mut agents_to_remove = Vec::new();
-                for (i, agent) in grid_lock.agents().iter().enumerate() {
-                    if agent.position.0 as usize == pos.0 as usize && agent.position.1 as usize == pos.1 as usize {
-                        agents_to_remove.push(i);
-                    }
-                }
-                
-                // Remove agents in reverse order to maintain indices
-                for &index in agents_to_remove.iter().rev() {
-                    if let Some(agent) = grid_lock.agents().get(index) {
-                        let delta = GridDelta::RemoveAgent(agent.id);
-                        grid_lock.apply_delta(delta);
-                    }
-                }
-                
-                // Remove bombs at explosion positions and track owners for restoration
-                for bomb_pos in bombs_to_remove.iter() {
-                    // Find and remove bombs at this position
-                    let mut i = 0;
-                    while i < grid_lock.bombs().len() {
-                        if grid_lock.bombs()[i].position == *bomb_pos {
-                            let bomb_to_remove = grid_lock.bombs_mut().remove(i);
-                            // Track this bomb's owner to restore their bomb count
-                            // Only restore if this bomb is actually exploding (timer = 0)
-                            if bomb_to_remove.timer == 0 {
-                                agents_to_restore_bombs.insert(bomb_to_remove.owner);
-                            }
-                            // Don't increment i since we removed an element
-                        } else {
-                            i += 1;
-                        }
-                    }
-                }
-            }
-            
-            // Restore bomb counts to agents whose bombs exploded
-            for agent_id in agents_to_restore_bombs {
-                if let Some(agent) = grid_lock.agents_mut().iter_mut().find(|a| a.id == agent_id) {
-                    agent.bombs_left = agent.bombs_left.saturating_add(1);
-                }
             }
-            
-            // Now remove the exploding bombs from the grid
-            for bomb_pos in &bombs_to_remove {
-                grid_lock.bombs_mut().retain(|b| b.position != *bomb_pos);
-            }
-            
-            drop(grid_lock);
-            
-            // Return a delta indicating explosion occurred
-            return Some(GridDelta::None);
         }
-        
         None
     }
 }
@@ -243,4 +218,154 @@ mod tests {
         system.run(&grid, &bus);
         // no assertion on content, just ensure call succeeds
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn blast_destroys_first_crate_in_reach() {
+        let mut system = BombSystem::new();
+        let mut grid = GameGrid::from_ascii("#######\n#1.++.#\n#######").unwrap();
+        grid.add_bomb(state::Bomb::new(state::BombId(0), 0, (2, 1), 1, 3));
+        let grid = Arc::new(RwLock::new(grid));
+        let bus = EventBus::new();
+        let (_id, rx) = bus.subscribe();
+        system.run(&grid, &bus);
+        let grid = grid.read().unwrap();
+        assert_eq!(grid.tile(3, 1), Some(Tile::Explosion));
+        assert_eq!(grid.tile(4, 1), Some(Tile::SoftCrate));
+        assert!(rx.try_iter().any(|e| e
+            == Event::bomb(BombEvent::CratesDestroyed {
+                bomb_id: state::BombId(0),
+                agent_id: 0,
+                positions: vec![(3, 1)],
+            })));
+    }
+
+    #[test]
+    fn chained_bombs_explode_together() {
+        let mut system = BombSystem::new();
+        let mut grid = GameGrid::from_ascii("#######\n#1...2#\n#######").unwrap();
+        grid.add_agent(state::AgentState {
+            bombs_left: 0,
+            ..state::AgentState::new(0, (1, 1))
+        });
+        grid.add_bomb(state::Bomb::new(state::BombId(0), 0, (1, 1), 1, 2));
+        grid.add_bomb(state::Bomb::new(state::BombId(1), 0, (3, 1), 3, 2));
+        let grid = Arc::new(RwLock::new(grid));
+        let bus = EventBus::new();
+        let (_id, rx) = bus.subscribe();
+        system.run(&grid, &bus);
+
+        let grid = grid.read().unwrap();
+        assert!(grid.bombs().is_empty());
+        assert_eq!(grid.agents()[0].bombs_left, 2);
+        assert!((1..=5).all(|x| grid.tile(x, 1) == Some(Tile::Explosion)));
+        let chained: Vec<_> = rx
+            .try_iter()
+            .filter_map(|e| match e {
+                Event::Bomb(BombEvent::ChainReaction { positions, .. }) => Some(positions),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(chained, vec![(1..=5).map(|x| (x, 1)).collect::<Vec<_>>()]);
+    }
+
+    #[test]
+    fn explosion_changes_are_broadcast_as_grid_deltas() {
+        let mut system = BombSystem::new();
+        let mut grid = GameGrid::from_ascii("#####\n#1.+#\n#####").unwrap();
+        grid.add_agent(state::AgentState {
+            bombs_left: 0,
+            ..state::AgentState::new(0, (1, 1))
+        });
+        grid.add_bomb(state::Bomb::new(state::BombId(0), 0, (2, 1), 1, 1));
+        let grid = Arc::new(RwLock::new(grid));
+        let bus = EventBus::new();
+        let (_id, rx) = bus.subscribe();
+        system.run(&grid, &bus);
+
+        let deltas: Vec<_> = rx
+            .try_iter()
+            .filter_map(|e| match e {
+                Event::Grid(delta) => Some(delta),
+                _ => None,
+            })
+            .collect();
+        assert!(deltas.contains(&GridDelta::RemoveBomb(state::BombId(0))));
+        assert!(deltas.iter().any(|d| matches!(
+            d,
+            GridDelta::UpdateAgent(agent) if agent.id == 0 && agent.bombs_left == 1
+        )));
+        assert!(deltas.contains(&GridDelta::SetTile {
+            x: 3,
+            y: 1,
+            tile: Tile::Explosion,
+        }));
+
+        // Replaying the broadcast deltas onto the original grid reproduces it.
+        let mut replayed = GameGrid::from_ascii("#####\n#1.+#\n#####").unwrap();
+        replayed.add_agent(state::AgentState {
+            bombs_left: 0,
+            ..state::AgentState::new(0, (1, 1))
+        });
+        replayed.add_bomb(state::Bomb::new(state::BombId(0), 0, (2, 1), 1, 1));
+        for delta in deltas {
+            replayed.apply_delta(delta);
+        }
+        let grid = grid.read().unwrap();
+        assert!(replayed.bombs().is_empty());
+        assert_eq!(replayed.agents(), grid.agents());
+        assert_eq!(replayed.tile(3, 1), grid.tile(3, 1));
+    }
+
+    fn sliding(id: u32, position: (u16, u16), timer: u8) -> state::Bomb {
+        state::Bomb {
+            sliding: Some(common::Direction::Right),
+            ..state::Bomb::new(state::BombId(id), 0, position, timer, 1)
+        }
+    }
+
+    #[test]
+    fn kicked_bomb_slides_until_blocked() {
+        let mut system = BombSystem::new();
+        let mut grid = GameGrid::from_ascii("########\n#1....+#\n#.....2#\n########").unwrap();
+        grid.add_bomb(sliding(0, (2, 1), 9));
+        grid.add_bomb(sliding(1, (1, 2), 9));
+        grid.add_agent(state::AgentState::new(5, (4, 2)));
+        let grid = Arc::new(RwLock::new(grid));
+        let bus = EventBus::new();
+        for _ in 0..4 {
+            system.run(&grid, &bus);
+        }
+        let grid = grid.read().unwrap();
+        let positions: Vec<_> = grid.bombs().iter().map(|b| (b.position, b.sliding)).collect();
+        // One bomb stops in front of the crate, the other in front of the agent.
+        assert_eq!(positions, vec![((5, 1), None), ((3, 2), None)]);
+    }
+
+    #[test]
+    fn sliding_bomb_explodes_where_its_timer_runs_out() {
+        let mut system = BombSystem::new();
+        let mut grid = GameGrid::from_ascii("########\n#1.....#\n########").unwrap();
+        grid.add_bomb(sliding(0, (2, 1), 2));
+        let grid = Arc::new(RwLock::new(grid));
+        let bus = EventBus::new();
+        system.run(&grid, &bus);
+        system.run(&grid, &bus);
+        let grid = grid.read().unwrap();
+        assert!(grid.bombs().is_empty());
+        assert_eq!(grid.tile(2, 1), Some(Tile::Empty));
+        assert!((3..=5).all(|x| grid.tile(x, 1) == Some(Tile::Explosion)));
+    }
+
+    #[test]
+    fn bomb_sliding_into_flames_goes_off() {
+        let mut system = BombSystem::new();
+        let mut grid = GameGrid::from_ascii("########\n#1.....#\n########").unwrap();
+        grid.set_tile(4, 1, Tile::Explosion);
+        grid.add_bomb(sliding(0, (3, 1), 9));
+        let grid = Arc::new(RwLock::new(grid));
+        system.run(&grid, &EventBus::new());
+        let grid = grid.read().unwrap();
+        assert!(grid.bombs().is_empty());
+        assert_eq!(grid.tile(5, 1), Some(Tile::Explosion));
+    }
+}
@@ -31,7 +31,7 @@ pub use bomb_system::BombSystem;
 pub use explosion::ExplosionSystem;
 pub use movement::MovementSystem;
 pub use player::PlayerSystem;
-pub use powerup::PowerupSystem;
+pub use powerup::{PowerupSystem, apply_powerup};
 
 #[cfg(test)]
 mod tests {
@@ -51,13 +51,16 @@ mod tests {
         engine.add_system(Box::new(PlayerSystem::new()));
         engine.add_system(Box::new(BombSystem::new()));
         engine.add_system(Box::new(ExplosionSystem::new()));
-        engine.add_system(Box::new(PowerupSystem::new()));
+        engine.add_system(Box::new(PowerupSystem::new(
+            crate::config::PowerUpRules::default(),
+            0,
+        )));
 
         engine.tick().await.unwrap();
 
         let grid_arc = engine.grid();
         let grid = grid_arc.read().unwrap();
         assert_eq!(grid.tile(0, 0), Some(Tile::Empty));
-        assert_eq!(grid.tile(1, 0), Some(Tile::PowerUp));
+        assert_eq!(grid.tile(1, 0), Some(Tile::Empty));
     }
 }
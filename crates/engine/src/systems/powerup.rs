@@ -1,17 +1,36 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::{Arc, RwLock};
 
-use events::bus::EventBus;
+use events::{bus::EventBus, events::Event};
+use rand::{Rng, SeedableRng, rngs::StdRng};
 use state::grid::{GameGrid, GridDelta, Tile};
+use state::{AgentState, PowerUpType};
 
 use super::System;
+use crate::config::PowerUpRules;
 
-/// Spawns powerups after explosions clear tiles.
-pub struct PowerupSystem;
+/// Drops power-ups where explosions destroyed soft crates.
+///
+/// A destroyed crate rolls once against the drop rates of
+/// [`PowerUpRules`]; the power-up appears once the explosion has cleared.
+pub struct PowerupSystem {
+    rules: PowerUpRules,
+    rng: StdRng,
+    /// Soft crates seen on the previous run.
+    crates: Option<BTreeSet<(usize, usize)>>,
+    /// Power-ups waiting for the explosion on their tile to clear.
+    pending: BTreeMap<(usize, usize), PowerUpType>,
+}
 
 impl PowerupSystem {
-    /// Create a new `PowerupSystem`.
-    pub fn new() -> Self {
-        Self
+    /// Create a new `PowerupSystem` rolling drops from `seed`.
+    pub fn new(rules: PowerUpRules, seed: u64) -> Self {
+        Self {
+            rules,
+            rng: StdRng::seed_from_u64(seed),
+            crates: None,
+            pending: BTreeMap::new(),
+        }
     }
 }
 
@@ -20,15 +39,172 @@ impl System for PowerupSystem {
         "powerup"
     }
 
-    fn run(&mut self, _grid: &Arc<RwLock<GameGrid>>, _events: &EventBus) -> Option<GridDelta> {
-        Some(GridDelta::SetTile {
-            x: 1,
-            y: 0,
-            tile: Tile::PowerUp,
-        })
+    fn run(&mut self, grid: &Arc<RwLock<GameGrid>>, events: &EventBus) -> Option<GridDelta> {
+        let mut grid = grid.write().expect("grid lock poisoned");
+        let mut crates = BTreeSet::new();
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                if grid.tile(x, y) == Some(Tile::SoftCrate) {
+                    crates.insert((x, y));
+                }
+            }
+        }
+        if let Some(previous) = self.crates.replace(crates) {
+            let current = self.crates.as_ref().expect("crates just stored");
+            for &(x, y) in previous.difference(current) {
+                if grid.tile(x, y) != Some(Tile::Explosion) {
+                    continue;
+                }
+                if let Some(kind) = self.rules.drop_for_roll(self.rng.gen()) {
+                    self.pending.insert((x, y), kind);
+                }
+            }
+        }
+
+        let revealed: Vec<_> = self
+            .pending
+            .iter()
+            .filter(|&(&(x, y), _)| grid.tile(x, y) != Some(Tile::Explosion))
+            .map(|(&pos, &kind)| (pos, kind))
+            .collect();
+        for ((x, y), kind) in revealed {
+            self.pending.remove(&(x, y));
+            if grid.tile(x, y) != Some(Tile::Empty) {
+                continue;
+            }
+            let delta = GridDelta::SetTile {
+                x,
+                y,
+                tile: Tile::PowerUp(kind),
+            };
+            grid.apply_delta(delta.clone());
+            events.broadcast(Event::Grid(delta));
+        }
+        None
     }
 
     fn dependencies(&self) -> &[&'static str] {
-        &["explosion"]
+        &["bomb"]
+    }
+}
+
+/// Apply the effect of collecting `kind` to `agent`, capped by `rules`.
+///
+/// `live_bombs` is how many of the agent's bombs are on the board; they
+/// count against `max_bombs` as they return to its stock.
+pub fn apply_powerup(
+    agent: &mut AgentState,
+    kind: PowerUpType,
+    live_bombs: usize,
+    rules: &PowerUpRules,
+) {
+    match kind {
+        PowerUpType::BombCount => {
+            if agent.bombs_left as usize + live_bombs < rules.max_bombs as usize {
+                agent.bombs_left += 1;
+            }
+        }
+        PowerUpType::BlastRadius => agent.power = (agent.power + 1).min(rules.max_power),
+        PowerUpType::Speed => agent.speed = (agent.speed + 1).min(rules.max_speed),
+        PowerUpType::Kick => agent.kick = true,
+        PowerUpType::Remote => agent.remote = true,
+        PowerUpType::Pierce => agent.pierce = true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn always(kind: PowerUpType) -> PowerUpRules {
+        let mut rules = PowerUpRules {
+            bomb_count: 0.0,
+            blast_radius: 0.0,
+            speed: 0.0,
+            kick: 0.0,
+            remote: 0.0,
+            pierce: 0.0,
+            ..PowerUpRules::default()
+        };
+        match kind {
+            PowerUpType::BombCount => rules.bomb_count = 1.0,
+            PowerUpType::BlastRadius => rules.blast_radius = 1.0,
+            PowerUpType::Speed => rules.speed = 1.0,
+            PowerUpType::Kick => rules.kick = 1.0,
+            PowerUpType::Remote => rules.remote = 1.0,
+            PowerUpType::Pierce => rules.pierce = 1.0,
+        }
+        rules
+    }
+
+    #[test]
+    fn destroyed_crate_drops_after_explosion_clears() {
+        let mut system = PowerupSystem::new(always(PowerUpType::Kick), 0);
+        let mut grid = GameGrid::new(3, 3);
+        grid.set_tile(1, 1, Tile::SoftCrate);
+        let grid = Arc::new(RwLock::new(grid));
+        let bus = EventBus::new();
+        system.run(&grid, &bus);
+
+        grid.write().unwrap().set_tile(1, 1, Tile::Explosion);
+        system.run(&grid, &bus);
+        assert_eq!(grid.read().unwrap().tile(1, 1), Some(Tile::Explosion));
+
+        grid.write().unwrap().set_tile(1, 1, Tile::Empty);
+        system.run(&grid, &bus);
+        assert_eq!(
+            grid.read().unwrap().tile(1, 1),
+            Some(Tile::PowerUp(PowerUpType::Kick))
+        );
+    }
+
+    #[test]
+    fn zero_rates_never_drop() {
+        let rules = PowerUpRules {
+            bomb_count: 0.0,
+            blast_radius: 0.0,
+            speed: 0.0,
+            kick: 0.0,
+            remote: 0.0,
+            pierce: 0.0,
+            ..PowerUpRules::default()
+        };
+        assert!((0..100).all(|i| rules.drop_for_roll(i as f32 / 100.0).is_none()));
+        assert_eq!(
+            always(PowerUpType::Pierce).drop_for_roll(0.99),
+            Some(PowerUpType::Pierce)
+        );
+    }
+
+    #[test]
+    fn pickups_raise_stats_up_to_limits() {
+        let rules = PowerUpRules {
+            max_power: 2,
+            ..PowerUpRules::default()
+        };
+        let mut agent = AgentState::new(0, (0, 0));
+        for _ in 0..3 {
+            apply_powerup(&mut agent, PowerUpType::BlastRadius, 0, &rules);
+        }
+        apply_powerup(&mut agent, PowerUpType::BombCount, 0, &rules);
+        apply_powerup(&mut agent, PowerUpType::Speed, 0, &rules);
+        apply_powerup(&mut agent, PowerUpType::Kick, 0, &rules);
+        assert_eq!(agent.power, 2);
+        assert_eq!(agent.bombs_left, 2);
+        assert_eq!(agent.speed, 2);
+        assert!(agent.kick && !agent.remote && !agent.pierce);
+    }
+
+    #[test]
+    fn live_bombs_count_against_the_bomb_limit() {
+        let rules = PowerUpRules {
+            max_bombs: 2,
+            ..PowerUpRules::default()
+        };
+        let mut agent = AgentState::new(0, (0, 0));
+        apply_powerup(&mut agent, PowerUpType::BombCount, 1, &rules);
+        assert_eq!(agent.bombs_left, 1);
+        apply_powerup(&mut agent, PowerUpType::BombCount, 0, &rules);
+        assert_eq!(agent.bombs_left, 2);
     }
 }
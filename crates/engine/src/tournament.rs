@@ -1,54 +1,140 @@
+mod archive;
+mod bracket;
+mod checkpoint;
 mod game_session;
+mod lobby;
+mod pool;
+mod rating;
 mod registry;
+mod rotation;
 mod scheduler;
 mod scoring;
+mod swiss;
 
-use game_session::GameSession;
+pub use archive::TournamentArchive;
+pub use bracket::{Bracket, BracketMatch};
+pub use checkpoint::Checkpoint;
+use lobby::RemoteLobby;
+use pool::MatchPool;
+pub use rating::{Rating, RatingBook};
+pub use registry::BotConnection;
 use registry::BotRegistry;
-use scheduler::GameScheduler;
-use scoring::{BotScore, ScoreTracker};
+use scheduler::{GameId, GameMatch, GameScheduler};
+use scoring::ScoreTracker;
+pub use scoring::{BotScore, Ranking};
 
-use crate::{config::TournamentConfig, config::UnifiedBotConfig as BotConfig, SystemHandle};
+use crate::{
+    config::TournamentConfig, config::UnifiedBotConfig as BotConfig, MatchOutcome, Replay,
+    SystemHandle,
+};
 use events::events::bot_events::BotId;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::time::Duration;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameResult {
-    pub winner: BotId,
+    pub outcome: MatchOutcome,
     pub participants: Vec<BotId>,
     pub survival_times: HashMap<BotId, Duration>,
     pub destruction_points: HashMap<BotId, u32>,
     pub powerups_collected: HashMap<BotId, u32>,
+    /// Seed the match was played with.
+    #[serde(default)]
+    pub seed: u64,
+    /// Grid deltas of the match, when replays were recorded.
+    #[serde(default)]
+    pub replay: Option<Replay>,
 }
 
 impl GameResult {
-    pub fn new(participants: Vec<BotId>, winner: BotId) -> Self {
+    pub fn new(participants: Vec<BotId>, outcome: MatchOutcome) -> Self {
         Self {
-            winner,
+            outcome,
             participants,
             survival_times: HashMap::new(),
             destruction_points: HashMap::new(),
             powerups_collected: HashMap::new(),
+            seed: 0,
+            replay: None,
+        }
+    }
+
+    /// The participant that won outright, if the match had a winner.
+    pub fn winner(&self) -> Option<BotId> {
+        self.outcome.winner()
+    }
+
+    /// Finishing place of every participant, counting from 1, best first.
+    ///
+    /// A timeout places bots by its ranking. Otherwise the winner, or every
+    /// bot sharing the draw, comes first and the rest follow by survival
+    /// time; bots that went down together share a place.
+    pub fn placements(&self) -> Vec<(BotId, u32)> {
+        // Greater keys place better; equal keys share a place.
+        let key = |bot: &BotId| {
+            let survived = self.survival_times.get(bot).copied().unwrap_or_default();
+            match &self.outcome {
+                MatchOutcome::Timeout { ranking } => match ranking.iter().position(|b| b == bot) {
+                    Some(i) => (true, ranking.len() - i, Duration::ZERO),
+                    None => (false, 0, survived),
+                },
+                MatchOutcome::Winner(id) => (id == bot, 0, survived),
+                MatchOutcome::Draw(ids) => (ids.contains(bot), 0, survived),
+            }
+        };
+        let mut order: Vec<_> = self
+            .participants
+            .iter()
+            .map(|bot| (*bot, key(bot)))
+            .collect();
+        order.sort_by(|a, b| b.1.cmp(&a.1));
+        let mut placements = Vec::with_capacity(order.len());
+        for (i, &(bot, key)) in order.iter().enumerate() {
+            let place = match placements.last() {
+                Some(&(_, place)) if order[i - 1].1 == key => place,
+                _ => i as u32 + 1,
+            };
+            placements.push((bot, place));
         }
+        placements
     }
 }
 
+/// Games played in one tournament round.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RoundRecord {
+    pub games: Vec<(GameId, GameResult)>,
+    /// Bot that sat the round out, in a Swiss tournament.
+    pub bye: Option<BotId>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ResultAggregator {
     pub results: Vec<GameResult>,
+    pub rounds: Vec<RoundRecord>,
 }
 
 impl ResultAggregator {
     pub fn new() -> Self {
         Self {
             results: Vec::new(),
+            rounds: Vec::new(),
         }
     }
 
     pub fn add_results(&mut self, res: Vec<GameResult>) {
         self.results.extend(res);
     }
+
+    pub fn add_round(&mut self, round: RoundRecord) {
+        self.add_results(round.games.iter().map(|(_, r)| r.clone()).collect());
+        self.rounds.push(round);
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -67,19 +153,35 @@ pub enum TournamentError {
     RegistrationClosed,
     #[error("game failed: {0}")]
     GameFailed(String),
+    #[error("game {0} timed out")]
+    GameTimedOut(scheduler::GameId),
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("invalid checkpoint: {0}")]
+    Checkpoint(String),
 }
 
 pub struct TournamentResults {
-    pub rankings: Vec<(BotId, BotScore, u32)>,
+    pub rankings: Vec<Ranking>,
+    /// Archive written for the tournament, when `persist_results` is set.
+    pub archive: Option<PathBuf>,
 }
 
 pub struct TournamentManager {
-    _config: TournamentConfig,
+    config: TournamentConfig,
     state: TournamentState,
     bot_registry: BotRegistry,
     game_scheduler: GameScheduler,
     score_tracker: ScoreTracker,
     result_aggregator: ResultAggregator,
+    ratings: Option<RatingBook>,
+    /// Games of the next round already played before a resume.
+    resumed: Vec<(GameId, GameResult)>,
+    /// Remote bots waiting for matches, once registration opened with
+    /// `allow_remote_bots` set.
+    lobby: Option<Arc<RemoteLobby>>,
     system_handle: SystemHandle,
 }
 
@@ -88,20 +190,35 @@ impl TournamentManager {
         let scheduler = GameScheduler::new(config.format.clone());
         let tracker = ScoreTracker::new(config.scoring_system.clone());
         Self {
-            _config: config,
+            config,
             state: TournamentState::Idle,
             bot_registry: BotRegistry::default(),
             game_scheduler: scheduler,
             score_tracker: tracker,
             result_aggregator: ResultAggregator::new(),
+            ratings: None,
+            resumed: Vec::new(),
+            lobby: None,
             system_handle,
         }
     }
 
+    /// Open registration.
+    ///
+    /// With `allow_remote_bots` set this also starts serving the remote bot
+    /// protocol on `remote_listen_addr`.
     pub async fn start_registration(&mut self) -> Result<(), TournamentError> {
         if self.state != TournamentState::Idle {
             return Err(TournamentError::InvalidState);
         }
+        if self.config.allow_remote_bots {
+            let lobby = RemoteLobby::bind(
+                &self.config.remote_listen_addr,
+                Duration::from_secs(self.config.registration_timeout_seconds),
+            )?;
+            info!("Serving remote bots on {}", lobby.local_addr()?);
+            self.lobby = Some(Arc::new(lobby));
+        }
         self.state = TournamentState::Registration;
         Ok(())
     }
@@ -113,34 +230,264 @@ impl TournamentManager {
         self.bot_registry.register_bot(bot_config)
     }
 
+    /// Register every remote bot that connects within `window`.
+    ///
+    /// A bot is registered under the name it announces, the first time that
+    /// name is seen, with the configured `remote_decision_timeout_ms`; later
+    /// connections under the same name wait for the bot's matches, the
+    /// registering one for its first. Registered ids are returned in
+    /// registration order.
+    pub async fn register_remote_bots(
+        &mut self,
+        window: Duration,
+    ) -> Result<Vec<BotId>, TournamentError> {
+        if self.state != TournamentState::Registration {
+            return Err(TournamentError::RegistrationClosed);
+        }
+        let Some(lobby) = self.lobby.clone() else {
+            return Err(TournamentError::InvalidState);
+        };
+        let deadline = Instant::now() + window;
+        let mut registered = Vec::new();
+        loop {
+            let admitting = Arc::clone(&lobby);
+            let admitted = tokio::task::spawn_blocking(move || admitting.admit(deadline))
+                .await
+                .map_err(|e| TournamentError::GameFailed(e.to_string()))?;
+            let Some((name, connection)) = admitted else {
+                break;
+            };
+            if self.bot_names().values().any(|known| *known == name) {
+                continue;
+            }
+            info!(
+                "Remote bot {} {} registered from {}",
+                name, connection.version, connection.peer
+            );
+            let config = BotConfig {
+                name,
+                ai_type: BotConfig::REMOTE_AI_TYPE.into(),
+                rl_mode: false,
+                rl_model_path: None,
+                decision_timeout_ms: self.config.remote_decision_timeout_ms,
+            };
+            registered.push(self.bot_registry.register_remote_bot(config, connection)?);
+        }
+        Ok(registered)
+    }
+
+    /// Address remote bots connect to, while remote bots are being served.
+    pub fn remote_address(&self) -> Option<SocketAddr> {
+        self.lobby.as_ref().and_then(|lobby| lobby.local_addr().ok())
+    }
+
+    /// How each remote bot connected when it registered.
+    pub fn remote_bots(&self) -> Vec<(BotId, &BotConnection)> {
+        self.bot_registry
+            .get_bot_ids()
+            .into_iter()
+            .filter_map(|id| Some((id, self.bot_registry.connection(id)?)))
+            .collect()
+    }
+
     pub async fn start_tournament(&mut self) -> Result<(), TournamentError> {
         if self.state != TournamentState::Registration {
             return Err(TournamentError::InvalidState);
         }
+        if let Some(path) = &self.config.ratings_file {
+            let book = RatingBook::load(path)?;
+            // Unrated bots are left out so they seed below every rated one.
+            let ratings = self
+                .bot_names()
+                .into_iter()
+                .filter_map(|(id, name)| Some((id, book.ratings.get(&name)?.rating)))
+                .collect();
+            self.game_scheduler.set_ratings(ratings);
+            self.ratings = Some(book);
+        }
         self.state = TournamentState::Running;
         Ok(())
     }
 
+    /// Ratings as of the last completed round, when a ratings file is configured.
+    pub fn ratings(&self) -> Option<&RatingBook> {
+        self.ratings.as_ref()
+    }
+
+    fn bot_names(&self) -> HashMap<BotId, String> {
+        self.bot_registry
+            .get_bot_ids()
+            .into_iter()
+            .filter_map(|id| Some((id, self.bot_registry.config(id)?.name.clone())))
+            .collect()
+    }
+
     pub fn has_next_round(&self) -> bool {
         self.game_scheduler.has_next_round()
     }
 
+    /// Prior ratings, used to seed brackets with [`Seeding::Rating`].
+    ///
+    /// [`Seeding::Rating`]: crate::config::Seeding::Rating
+    pub fn set_ratings(&mut self, ratings: HashMap<BotId, f64>) {
+        self.game_scheduler.set_ratings(ratings);
+    }
+
+    /// The bracket of a single-elimination tournament, once it has started.
+    pub fn bracket(&self) -> Option<&Bracket> {
+        self.game_scheduler.bracket()
+    }
+
     pub async fn run_next_round(&mut self) -> Result<Vec<GameResult>, TournamentError> {
         if self.state != TournamentState::Running {
             return Err(TournamentError::InvalidState);
         }
+        let matches = self.schedule_round();
+        // Games of this round finished before a resume are not played again.
+        let mut played = std::mem::take(&mut self.resumed);
+        played.retain(|(id, _)| matches.iter().any(|m| m.id == *id));
+        let mut pending = Vec::with_capacity(matches.len());
+        for m in matches
+            .iter()
+            .filter(|m| played.iter().all(|(id, _)| *id != m.id))
+        {
+            let participants = m
+                .participants
+                .iter()
+                .map(|&id| {
+                    self.bot_registry
+                        .config(id)
+                        .cloned()
+                        .map(|config| (id, config))
+                        .ok_or_else(|| {
+                            TournamentError::GameFailed(format!("bot {} is not registered", id))
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            pending.push((m.id, participants));
+        }
+        let mut checkpoint = self
+            .config
+            .checkpoint_file
+            .clone()
+            .map(|path| (path, self.checkpoint(played.clone())));
+        // Failed games are logged by the pool and left out of the round.
+        let pool = MatchPool::new(
+            self.config.max_concurrent_games,
+            Duration::from_secs(self.config.game_timeout_seconds),
+        )
+        .recording_replays(self.config.persist_results)
+        .with_lobby(self.lobby.clone());
+        let results = pool
+            .run(
+                self.system_handle.engine().config(),
+                pending,
+                |id, result| {
+                    if let Some((path, checkpoint)) = &mut checkpoint {
+                        checkpoint.current.push((id, result.clone()));
+                        if let Err(e) = checkpoint.save(path) {
+                            warn!("could not save checkpoint: {}", e);
+                        }
+                    }
+                },
+            )
+            .await;
+        played.extend(
+            results
+                .into_iter()
+                .filter_map(|(id, result)| result.ok().map(|result| (id, result))),
+        );
+        played.sort_by_key(|(id, _)| *id);
+        let results = self.finish_round(played);
+        if let Some(path) = &self.config.checkpoint_file {
+            self.checkpoint(Vec::new()).save(path)?;
+        }
+        Ok(results)
+    }
+
+    /// Pick up a tournament from the checkpoint at `path`.
+    ///
+    /// The finished rounds are scheduled again and their results applied
+    /// without playing them; the games of the interrupted round that
+    /// finished are skipped by the next [`TournamentManager::run_next_round`].
+    pub async fn resume(
+        path: impl AsRef<Path>,
+        system_handle: SystemHandle,
+    ) -> Result<Self, TournamentError> {
+        let checkpoint = Checkpoint::load(path)?;
+        let mut manager = Self::new(checkpoint.config, system_handle);
+        manager.start_registration().await?;
+        for (id, config) in checkpoint.bots {
+            if manager.register_bot(config).await? != id {
+                return Err(TournamentError::Checkpoint(format!(
+                    "bot {} cannot be registered again",
+                    id
+                )));
+            }
+        }
+        manager.start_tournament().await?;
+        for round in checkpoint.rounds {
+            let matches = manager.schedule_round();
+            if round
+                .games
+                .iter()
+                .any(|(id, _)| matches.iter().all(|m| m.id != *id))
+            {
+                return Err(TournamentError::Checkpoint(
+                    "finished rounds no longer match the schedule".into(),
+                ));
+            }
+            manager.finish_round(round.games);
+        }
+        manager.resumed = checkpoint.current;
+        Ok(manager)
+    }
+
+    /// Schedule the next round, crediting its bye if there is one.
+    fn schedule_round(&mut self) -> Vec<GameMatch> {
         let bots = self.bot_registry.get_bot_ids();
         let matches = self.game_scheduler.schedule_next_round(&bots);
-        let mut results = Vec::new();
-        for m in matches {
-            let mut session = GameSession::new(m.id, m.participants.clone());
-            session.start(&self.system_handle).await?;
-            let res = session.wait_for_completion().await?;
-            results.push(res);
+        if let Some(bot) = self.game_scheduler.bye() {
+            self.score_tracker.record_bye(bot);
         }
+        matches
+    }
+
+    /// Apply the results of the round scheduled last.
+    fn finish_round(&mut self, played: Vec<(GameId, GameResult)>) -> Vec<GameResult> {
+        self.game_scheduler.record_results(&played);
+        let results: Vec<GameResult> = played.iter().map(|(_, result)| result.clone()).collect();
         self.score_tracker.update_scores(&results);
-        self.result_aggregator.add_results(results.clone());
-        Ok(results)
+        if self.ratings.is_some() {
+            let names = self.bot_names();
+            let book = self.ratings.as_mut().expect("ratings are loaded");
+            for result in &results {
+                book.record(result, &names);
+            }
+        }
+        self.result_aggregator.add_round(RoundRecord {
+            games: played,
+            bye: self.game_scheduler.bye(),
+        });
+        results
+    }
+
+    /// Progress so far, with `current` the finished games of the round in progress.
+    fn checkpoint(&self, current: Vec<(GameId, GameResult)>) -> Checkpoint {
+        Checkpoint {
+            config: self.config.clone(),
+            bots: self.registered_bots(),
+            rounds: self.result_aggregator.rounds.clone(),
+            current,
+        }
+    }
+
+    fn registered_bots(&self) -> Vec<(BotId, BotConfig)> {
+        self.bot_registry
+            .get_bot_ids()
+            .into_iter()
+            .filter_map(|id| Some((id, self.bot_registry.config(id)?.clone())))
+            .collect()
     }
 
     pub async fn finalize_tournament(&mut self) -> Result<TournamentResults, TournamentError> {
@@ -148,8 +495,29 @@ impl TournamentManager {
             return Err(TournamentError::InvalidState);
         }
         self.state = TournamentState::Completed;
+        if let (Some(book), Some(path)) = (&self.ratings, &self.config.ratings_file) {
+            book.save(path)?;
+        }
+        if let Some(path) = &self.config.checkpoint_file {
+            match std::fs::remove_file(path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+                _ => {}
+            }
+        }
         let rankings = self.score_tracker.get_rankings();
-        Ok(TournamentResults { rankings })
+        let archive = if self.config.persist_results {
+            let archive = TournamentArchive::new(
+                self.config.clone(),
+                self.system_handle.engine().config().clone(),
+                self.registered_bots(),
+                self.result_aggregator.rounds.clone(),
+                rankings.clone(),
+            );
+            Some(archive.save(&self.config.results_dir)?)
+        } else {
+            None
+        };
+        Ok(TournamentResults { rankings, archive })
     }
 }
 
@@ -161,17 +529,17 @@ mod tests {
     use std::sync::{Arc, RwLock};
 
     fn dummy_handle() -> SystemHandle {
-        let cfg = EngineConfig::default();
+        let mut cfg = EngineConfig::default();
+        cfg.rules.limits.max_ticks = Some(10);
         let grid = Arc::new(RwLock::new(state::GameGrid::new(5, 5)));
         let (engine, _) = Engine::with_components(cfg, grid, Arc::new(EventBus::new()));
         SystemHandle::new(Arc::new(EventBus::new()), engine, 0, None)
     }
 
-    #[test]
-    fn full_flow() {
-        let config = TournamentConfig {
+    fn tournament_config(format: TournamentFormat) -> TournamentConfig {
+        TournamentConfig {
             name: "test".into(),
-            format: TournamentFormat::RoundRobin { total_rounds: 1 },
+            format,
             max_concurrent_games: 1,
             game_timeout_seconds: 60,
             scoring_system: ScoringSystem::WinLoss {
@@ -180,22 +548,35 @@ mod tests {
             },
             registration_timeout_seconds: 1,
             allow_remote_bots: false,
+            remote_listen_addr: "127.0.0.1:0".into(),
+            remote_decision_timeout_ms: 100,
             persist_results: false,
-        };
+            results_dir: "results".into(),
+            ratings_file: None,
+            checkpoint_file: None,
+        }
+    }
+
+    fn bot(name: &str) -> BotConfig {
+        BotConfig {
+            name: name.into(),
+            ai_type: "Heuristic".into(),
+            rl_mode: false,
+            rl_model_path: None,
+            decision_timeout_ms: 10,
+        }
+    }
+
+    #[test]
+    fn full_flow() {
+        let config = tournament_config(TournamentFormat::RoundRobin { total_rounds: 1 });
         let handle = dummy_handle();
         let mut tm = TournamentManager::new(config, handle);
         let rt = tokio::runtime::Runtime::new().unwrap();
         rt.block_on(async {
             tm.start_registration().await.unwrap();
-            let bot_cfg = BotConfig {
-                name: "b1".into(),
-                ai_type: "Heuristic".into(),
-                rl_mode: false,
-                rl_model_path: None,
-                decision_timeout_ms: 10,
-            };
-            tm.register_bot(bot_cfg.clone()).await.unwrap();
-            tm.register_bot(bot_cfg).await.unwrap();
+            tm.register_bot(bot("b1")).await.unwrap();
+            tm.register_bot(bot("b1")).await.unwrap();
             tm.start_tournament().await.unwrap();
             while tm.has_next_round() {
                 let res = tm.run_next_round().await.unwrap();
@@ -205,4 +586,226 @@ mod tests {
             assert_eq!(finals.rankings.len(), 2);
         });
     }
-}
+
+    #[test]
+    fn ratings_seed_the_bracket_and_carry_over() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ratings.json");
+        let mut book = RatingBook::default();
+        book.ratings.insert(
+            "strong".into(),
+            Rating {
+                rating: 1700.0,
+                games: 10,
+            },
+        );
+        book.save(&path).unwrap();
+
+        let mut config = tournament_config(TournamentFormat::SingleElimination {
+            bracket_size: 2,
+            seeding: crate::config::Seeding::Rating,
+        });
+        config.ratings_file = Some(path.clone());
+        let mut tm = TournamentManager::new(config, dummy_handle());
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            tm.start_registration().await.unwrap();
+            tm.register_bot(bot("weak")).await.unwrap();
+            let strong = tm.register_bot(bot("strong")).await.unwrap();
+            tm.start_tournament().await.unwrap();
+            while tm.has_next_round() {
+                tm.run_next_round().await.unwrap();
+            }
+            assert_eq!(tm.bracket().unwrap().seed(strong), Some(1));
+            tm.finalize_tournament().await.unwrap();
+        });
+        let book = RatingBook::load(&path).unwrap();
+        assert_eq!(book.ratings["strong"].games, 11);
+        assert_eq!(book.ratings["weak"].games, 1);
+    }
+
+    #[test]
+    fn unrated_bots_seed_below_rated_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ratings.json");
+        let mut book = RatingBook::default();
+        book.ratings.insert(
+            "rated".into(),
+            Rating {
+                rating: 1400.0,
+                games: 10,
+            },
+        );
+        book.save(&path).unwrap();
+
+        let mut config = tournament_config(TournamentFormat::SingleElimination {
+            bracket_size: 2,
+            seeding: crate::config::Seeding::Rating,
+        });
+        config.ratings_file = Some(path);
+        let mut tm = TournamentManager::new(config, dummy_handle());
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        rt.block_on(async {
+            tm.start_registration().await.unwrap();
+            let unrated = tm.register_bot(bot("unrated")).await.unwrap();
+            let rated = tm.register_bot(bot("rated")).await.unwrap();
+            tm.start_tournament().await.unwrap();
+            tm.run_next_round().await.unwrap();
+            assert_eq!(tm.bracket().unwrap().seed(rated), Some(1));
+            assert_eq!(tm.bracket().unwrap().seed(unrated), Some(2));
+        });
+    }
+
+    #[test]
+    fn finished_tournaments_are_archived() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = tournament_config(TournamentFormat::Swiss { rounds: 2 });
+        config.persist_results = true;
+        config.results_dir = dir.path().join("results");
+        let mut tm = TournamentManager::new(config, dummy_handle());
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let finals = rt.block_on(async {
+            tm.start_registration().await.unwrap();
+            for name in ["a", "b", "c"] {
+                tm.register_bot(bot(name)).await.unwrap();
+            }
+            tm.start_tournament().await.unwrap();
+            while tm.has_next_round() {
+                tm.run_next_round().await.unwrap();
+            }
+            tm.finalize_tournament().await.unwrap()
+        });
+
+        let archive = TournamentArchive::load(finals.archive.unwrap()).unwrap();
+        assert_eq!(archive.bots.len(), 3);
+        assert_eq!(archive.rounds.len(), 2);
+        assert!(archive.rounds.iter().all(|r| r.bye.is_some()));
+        let (id, game) = &archive.rounds[1].games[0];
+        assert_eq!(game.seed, archive.engine.seed + *id as u64);
+        assert!(game.replay.is_some());
+        let bots = |rankings: &[Ranking]| rankings.iter().map(|r| r.bot).collect::<Vec<_>>();
+        let rerank = archive.rerank(archive.config.scoring_system.clone());
+        assert_eq!(bots(&rerank), bots(&finals.rankings));
+    }
+
+    #[test]
+    fn interrupted_tournaments_resume_from_their_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("checkpoint.json");
+        let mut config = tournament_config(TournamentFormat::Swiss { rounds: 2 });
+        config.checkpoint_file = Some(path.clone());
+        let mut tm = TournamentManager::new(config, dummy_handle());
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let first = rt.block_on(async {
+            tm.start_registration().await.unwrap();
+            for name in ["a", "b", "c", "d"] {
+                tm.register_bot(bot(name)).await.unwrap();
+            }
+            tm.start_tournament().await.unwrap();
+            tm.run_next_round().await.unwrap()
+        });
+        drop(tm);
+
+        // Pretend the process died after the first game of round two.
+        let mut checkpoint = Checkpoint::load(&path).unwrap();
+        assert_eq!(checkpoint.rounds.len(), 1);
+        assert!(checkpoint.current.is_empty());
+        let mut tm = rt
+            .block_on(TournamentManager::resume(&path, dummy_handle()))
+            .unwrap();
+        let game = tm.schedule_round().remove(0);
+        let winner = MatchOutcome::Winner(game.participants[0]);
+        let mut done = GameResult::new(game.participants, winner);
+        done.seed = 4242;
+        checkpoint.current = vec![(game.id, done)];
+        checkpoint.save(&path).unwrap();
+
+        let mut tm = rt
+            .block_on(TournamentManager::resume(&path, dummy_handle()))
+            .unwrap();
+        let finals = rt.block_on(async {
+            assert_eq!(tm.result_aggregator.rounds[0].games.len(), first.len());
+            let round = tm.run_next_round().await.unwrap();
+            assert_eq!(round.len(), 2);
+            assert_eq!(round[0].seed, 4242);
+            assert!(!tm.has_next_round());
+            tm.finalize_tournament().await.unwrap()
+        });
+        assert_eq!(finals.rankings.len(), 4);
+        assert!(!path.exists());
+    }
+
+    /// Connect as a remote bot called `name`, standing still every tick,
+    /// and return the messages of the match once it stops.
+    fn remote_walker(addr: SocketAddr, name: &str) -> Vec<crate::bot::remote::ServerMessage> {
+        use crate::bot::remote::{ClientMessage, PROTOCOL_VERSION, ServerMessage};
+        use std::io::{BufRead, BufReader, Write};
+
+        let mut writer = std::net::TcpStream::connect(addr).unwrap();
+        let mut reader = BufReader::new(writer.try_clone().unwrap());
+        let mut send = |message: &ClientMessage| {
+            let mut line = serde_json::to_string(message).unwrap();
+            line.push('\n');
+            writer.write_all(line.as_bytes()).unwrap();
+        };
+        send(&ClientMessage::Hello {
+            name: name.into(),
+            version: "0.1.0".into(),
+            protocol: PROTOCOL_VERSION,
+        });
+        let mut seen = Vec::new();
+        let mut line = String::new();
+        while reader.read_line(&mut line).unwrap() > 0 {
+            let message: ServerMessage = serde_json::from_str(&line).unwrap();
+            line.clear();
+            if let ServerMessage::Observation { tick, .. } = message {
+                send(&ClientMessage::Decision {
+                    tick,
+                    decision: events::events::BotDecision::Wait,
+                });
+            }
+            let stopped = message == ServerMessage::Stopped;
+            seen.push(message);
+            if stopped {
+                break;
+            }
+        }
+        seen
+    }
+
+    #[test]
+    fn remote_bots_register_and_play() {
+        let mut config = tournament_config(TournamentFormat::RoundRobin { total_rounds: 1 });
+        config.allow_remote_bots = true;
+        let mut tm = TournamentManager::new(config, dummy_handle());
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let (remote, results, seen) = rt.block_on(async {
+            tm.start_registration().await.unwrap();
+            tm.register_bot(bot("local")).await.unwrap();
+            let addr = tm.remote_address().unwrap();
+            let client = std::thread::spawn(move || remote_walker(addr, "walker"));
+            let remote = tm
+                .register_remote_bots(Duration::from_millis(500))
+                .await
+                .unwrap();
+            tm.start_tournament().await.unwrap();
+            let mut results = Vec::new();
+            while tm.has_next_round() {
+                results.extend(tm.run_next_round().await.unwrap());
+            }
+            (remote, results, client.join().unwrap())
+        });
+
+        assert_eq!(remote, vec![1]);
+        let bots = tm.remote_bots();
+        assert_eq!(bots.len(), 1);
+        assert_eq!(bots[0].1.version, "0.1.0");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].participants, vec![0, 1]);
+        assert!(matches!(
+            seen.first(),
+            Some(crate::bot::remote::ServerMessage::Welcome { .. })
+        ));
+        assert_eq!(seen.last(), Some(&crate::bot::remote::ServerMessage::Stopped));
+    }
+}
\ No newline at end of file
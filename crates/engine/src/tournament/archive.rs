@@ -0,0 +1,82 @@
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use events::events::bot_events::BotId;
+use serde::{Deserialize, Serialize};
+
+use super::scoring::{Ranking, ScoreTracker};
+use super::{RoundRecord, TournamentError};
+use crate::config::{EngineConfig, ScoringSystem, TournamentConfig, UnifiedBotConfig as BotConfig};
+
+/// Everything needed to re-inspect a finished tournament offline.
+///
+/// Every game keeps the seed it was played with, and its replay when the
+/// tournament recorded them, so matches can be watched or replayed again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentArchive {
+    pub config: TournamentConfig,
+    /// Engine configuration the games were played with.
+    pub engine: EngineConfig,
+    pub bots: Vec<(BotId, BotConfig)>,
+    pub rounds: Vec<RoundRecord>,
+    pub rankings: Vec<Ranking>,
+    /// Seconds since the Unix epoch when the tournament finished.
+    pub finished_at: u64,
+}
+
+impl TournamentArchive {
+    pub fn new(
+        config: TournamentConfig,
+        engine: EngineConfig,
+        bots: Vec<(BotId, BotConfig)>,
+        rounds: Vec<RoundRecord>,
+        rankings: Vec<Ranking>,
+    ) -> Self {
+        let finished_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Self {
+            config,
+            engine,
+            bots,
+            rounds,
+            rankings,
+            finished_at,
+        }
+    }
+
+    /// Write the archive into `dir`, creating it if needed, and return the
+    /// path of the file, named after the tournament and its finishing time.
+    pub fn save(&self, dir: impl AsRef<Path>) -> Result<PathBuf, TournamentError> {
+        std::fs::create_dir_all(&dir)?;
+        let name: String = self
+            .config
+            .name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let path = dir
+            .as_ref()
+            .join(format!("{}-{}.json", name, self.finished_at));
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(path)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, TournamentError> {
+        let data = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Rank the archived games again under `scoring_system`.
+    pub fn rerank(&self, scoring_system: ScoringSystem) -> Vec<Ranking> {
+        let mut tracker = ScoreTracker::new(scoring_system);
+        for round in &self.rounds {
+            if let Some(bot) = round.bye {
+                tracker.record_bye(bot);
+            }
+            let results: Vec<_> = round.games.iter().map(|(_, r)| r.clone()).collect();
+            tracker.update_scores(&results);
+        }
+        tracker.get_rankings()
+    }
+}
@@ -0,0 +1,263 @@
+use std::fmt;
+
+use events::events::bot_events::BotId;
+
+use super::scheduler::{GameId, GameMatch};
+use super::GameResult;
+use crate::MatchOutcome;
+
+/// One pairing of a single-elimination bracket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BracketMatch {
+    /// Game playing this pairing, once scheduled. Byes are never scheduled.
+    pub game_id: Option<GameId>,
+    /// The two players, the better seed first; `None` is a bye.
+    pub players: [Option<BotId>; 2],
+    /// Player advancing to the next round, once known.
+    pub winner: Option<BotId>,
+}
+
+/// Single-elimination bracket.
+///
+/// Players are placed by seed so that the best seeds meet as late as
+/// possible, and the top seeds get the byes of fields that do not fill a
+/// power of two. A game without an outright winner advances the player
+/// ranked higher by the outcome, and a drawn or failed game advances the
+/// better seed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bracket {
+    /// Players, best seed first.
+    seeds: Vec<BotId>,
+    rounds: Vec<Vec<BracketMatch>>,
+}
+
+/// Bracket positions of the seeds `1..=size` for a power-of-two `size`, in
+/// bracket order: `[1, 8, 4, 5, 2, 7, 3, 6]` for eight slots.
+fn seed_positions(size: usize) -> Vec<usize> {
+    let mut positions = vec![1];
+    while positions.len() < size {
+        let sum = positions.len() * 2 + 1;
+        positions = positions.iter().flat_map(|&s| [s, sum - s]).collect();
+    }
+    positions
+}
+
+impl Bracket {
+    /// Build the first round for `seeds`, best first, in a bracket of at
+    /// least `slots` places.
+    pub fn new(seeds: Vec<BotId>, slots: usize) -> Self {
+        let size = slots.max(seeds.len()).max(2).next_power_of_two();
+        let player = |seed: usize| seeds.get(seed - 1).copied();
+        let first = seed_positions(size)
+            .chunks(2)
+            .map(|pair| {
+                let players = [player(pair[0]), player(pair[1])];
+                BracketMatch {
+                    game_id: None,
+                    players,
+                    winner: if players[1].is_none() {
+                        players[0]
+                    } else {
+                        None
+                    },
+                }
+            })
+            .collect();
+        Self {
+            seeds,
+            rounds: vec![first],
+        }
+    }
+
+    pub fn rounds(&self) -> &[Vec<BracketMatch>] {
+        &self.rounds
+    }
+
+    /// Seed of `bot`, counting from 1.
+    pub fn seed(&self, bot: BotId) -> Option<usize> {
+        self.seeds.iter().position(|&b| b == bot).map(|i| i + 1)
+    }
+
+    /// Winner of the final, once it has been played.
+    pub fn champion(&self) -> Option<BotId> {
+        match self.rounds.last().map(Vec::as_slice) {
+            Some([last]) => last.winner,
+            _ => None,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.seeds.is_empty() || self.champion().is_some()
+    }
+
+    /// Schedule the games of the next round, numbering them from `next_id`.
+    ///
+    /// Games of the previous round that are still undecided advance the
+    /// better seed. Returns no games once the bracket is finished.
+    pub fn schedule(&mut self, next_id: &mut GameId) -> Vec<GameMatch> {
+        loop {
+            let round = self.rounds.last_mut().expect("bracket has a first round");
+            let mut games = Vec::new();
+            for m in round.iter_mut().filter(|m| m.winner.is_none()) {
+                match (m.game_id, m.players) {
+                    (Some(_), _) => m.winner = m.players[0],
+                    (None, [Some(a), Some(b)]) => {
+                        m.game_id = Some(*next_id);
+                        games.push(GameMatch {
+                            id: *next_id,
+                            participants: vec![a, b],
+                        });
+                        *next_id += 1;
+                    }
+                    (None, _) => {}
+                }
+            }
+            if !games.is_empty() || round.len() == 1 {
+                return games;
+            }
+            let finished = self.rounds.last().expect("bracket has a first round");
+            let round = finished
+                .chunks(2)
+                .map(|pair| {
+                    let players = self.by_seed([pair[0].winner, pair[1].winner]);
+                    BracketMatch {
+                        game_id: None,
+                        players,
+                        winner: if players[1].is_none() {
+                            players[0]
+                        } else {
+                            None
+                        },
+                    }
+                })
+                .collect();
+            self.rounds.push(round);
+        }
+    }
+
+    /// Record the result of game `game_id` of the current round.
+    pub fn record(&mut self, game_id: GameId, result: &GameResult) {
+        let seeds = self.seeds.clone();
+        let seed = |bot: &BotId| seeds.iter().position(|b| b == bot).unwrap_or(usize::MAX);
+        let Some(m) = self
+            .rounds
+            .last_mut()
+            .and_then(|round| round.iter_mut().find(|m| m.game_id == Some(game_id)))
+        else {
+            return;
+        };
+        let players: Vec<BotId> = m.players.iter().flatten().copied().collect();
+        m.winner = match &result.outcome {
+            MatchOutcome::Winner(id) if players.contains(id) => Some(*id),
+            MatchOutcome::Timeout { ranking } => {
+                ranking.iter().find(|id| players.contains(id)).copied()
+            }
+            MatchOutcome::Draw(ids) => ids
+                .iter()
+                .filter(|id| players.contains(id))
+                .min_by_key(|id| seed(id))
+                .copied(),
+            MatchOutcome::Winner(_) => None,
+        };
+    }
+
+    /// `players` ordered better seed first.
+    fn by_seed(&self, mut players: [Option<BotId>; 2]) -> [Option<BotId>; 2] {
+        let rank = |p: &Option<BotId>| p.and_then(|b| self.seed(b)).unwrap_or(usize::MAX);
+        if rank(&players[1]) < rank(&players[0]) {
+            players.swap(0, 1);
+        }
+        players
+    }
+}
+
+impl fmt::Display for Bracket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = |player: Option<BotId>| match player {
+            Some(bot) => format!("[{}] bot {}", self.seed(bot).unwrap_or(0), bot),
+            None => "bye".to_string(),
+        };
+        for (i, round) in self.rounds.iter().enumerate() {
+            writeln!(f, "Round {}", i + 1)?;
+            for m in round {
+                let winner = match m.winner {
+                    Some(bot) => format!("bot {}", bot),
+                    None => "?".to_string(),
+                };
+                writeln!(
+                    f,
+                    "  {:<16} vs {:<16} -> {}",
+                    name(m.players[0]),
+                    name(m.players[1]),
+                    winner
+                )?;
+            }
+        }
+        if let Some(champion) = self.champion() {
+            writeln!(f, "Champion: bot {}", champion)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn won_by(winner: BotId, participants: Vec<BotId>) -> GameResult {
+        GameResult::new(participants, MatchOutcome::Winner(winner))
+    }
+
+    #[test]
+    fn top_seeds_get_byes() {
+        assert_eq!(seed_positions(8), vec![1, 8, 4, 5, 2, 7, 3, 6]);
+        let bracket = Bracket::new(vec![10, 11, 12, 13, 14], 4);
+        let first = &bracket.rounds()[0];
+        assert_eq!(first.len(), 4);
+        assert_eq!(first[0].players, [Some(10), None]);
+        assert_eq!(first[0].winner, Some(10));
+        assert_eq!(first[1].players, [Some(13), Some(14)]);
+        assert_eq!(first[2].winner, Some(11));
+        assert_eq!(first[3].winner, Some(12));
+    }
+
+    #[test]
+    fn winners_advance_until_a_champion_is_left() {
+        let mut bracket = Bracket::new(vec![1, 2, 3], 2);
+        let mut next_id = 0;
+        let games = bracket.schedule(&mut next_id);
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].participants, vec![2, 3]);
+        bracket.record(games[0].id, &won_by(3, vec![2, 3]));
+
+        let games = bracket.schedule(&mut next_id);
+        assert_eq!(games[0].id, 1);
+        assert_eq!(games[0].participants, vec![1, 3]);
+        let draw = GameResult::new(vec![1, 3], MatchOutcome::Draw(vec![1, 3]));
+        bracket.record(1, &draw);
+        assert!(bracket.schedule(&mut next_id).is_empty());
+        assert_eq!(bracket.champion(), Some(1));
+        assert!(bracket.is_finished());
+
+        let printed = bracket.to_string();
+        assert!(printed.contains("[2] bot 2        vs [3] bot 3        -> bot 3"));
+        assert!(printed.ends_with("Champion: bot 1\n"), "{}", printed);
+    }
+
+    #[test]
+    fn unplayed_games_advance_the_better_seed() {
+        let mut bracket = Bracket::new(vec![5, 6, 7, 8], 4);
+        let mut next_id = 0;
+        let games = bracket.schedule(&mut next_id);
+        assert_eq!(games.len(), 2);
+        let timeout = GameResult::new(
+            vec![6, 7],
+            MatchOutcome::Timeout {
+                ranking: vec![7, 6],
+            },
+        );
+        bracket.record(games[1].id, &timeout);
+        let games = bracket.schedule(&mut next_id);
+        assert_eq!(games[0].participants, vec![5, 7]);
+    }
+}
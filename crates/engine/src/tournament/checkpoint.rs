@@ -0,0 +1,40 @@
+use std::path::Path;
+
+use events::events::bot_events::BotId;
+use serde::{Deserialize, Serialize};
+
+use super::scheduler::GameId;
+use super::{GameResult, RoundRecord, TournamentError};
+use crate::config::{TournamentConfig, UnifiedBotConfig as BotConfig};
+
+/// Progress of a running tournament, saved after every match.
+///
+/// Scheduling is deterministic, so the registered bots and the results so
+/// far are enough to rebuild the scheduler, the scores and the ratings by
+/// scheduling the finished rounds again and feeding their results back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub config: TournamentConfig,
+    pub bots: Vec<(BotId, BotConfig)>,
+    /// Rounds played to the end.
+    pub rounds: Vec<RoundRecord>,
+    /// Games of the round in progress that have finished.
+    pub current: Vec<(GameId, GameResult)>,
+}
+
+impl Checkpoint {
+    /// Write the checkpoint to `path`, replacing the previous one only once
+    /// the new one is complete.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), TournamentError> {
+        let path = path.as_ref();
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_string(self)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, TournamentError> {
+        let data = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+}
@@ -1,16 +1,30 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use events::bus::{EventBus, EventFilter};
 use events::events::bot_events::BotId;
+use events::events::{BombEvent, Event, GameEvent};
 
-use crate::SystemHandle;
+use crate::add_match_systems;
+use crate::config::{EngineConfig, UnifiedBotConfig as BotConfig};
+use crate::engine::Engine;
 
+use super::lobby::RemoteLobby;
 use super::scheduler::GameId;
 use super::{GameResult, TournamentError};
 
 #[derive(Debug)]
 pub struct GameSession {
-    pub _id: GameId,
+    pub id: GameId,
     pub participants: Vec<BotId>,
+    configs: Vec<BotConfig>,
     pub state: SessionState,
     pub result: Option<GameResult>,
+    /// Keep a replay of the match in its result.
+    pub record_replay: bool,
+    /// Where remote participants connect from, when remote bots are allowed.
+    pub lobby: Option<Arc<RemoteLobby>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -20,22 +34,179 @@ pub enum SessionState {
     Completed,
 }
 
+/// Per-bot statistics gathered from the events of a match, keyed by engine bot id.
+#[derive(Debug, Default)]
+struct MatchStats {
+    eliminated_at: HashMap<BotId, u64>,
+    crates_destroyed: HashMap<BotId, u32>,
+    powerups_collected: HashMap<BotId, u32>,
+}
+
+impl MatchStats {
+    fn record(&mut self, event: Event) {
+        match event {
+            Event::Game(GameEvent::AgentEliminated { entity_id, tick }) => {
+                self.eliminated_at.insert(entity_id, tick);
+            }
+            Event::Bomb(BombEvent::CratesDestroyed {
+                agent_id,
+                positions,
+                ..
+            }) => {
+                *self.crates_destroyed.entry(agent_id).or_default() += positions.len() as u32;
+            }
+            Event::Bomb(BombEvent::PowerUpCollected { agent_id, .. }) => {
+                *self.powerups_collected.entry(agent_id).or_default() += 1;
+            }
+            _ => {}
+        }
+    }
+}
+
 impl GameSession {
-    pub fn new(id: GameId, participants: Vec<BotId>) -> Self {
-        Self { _id: id, participants, state: SessionState::Scheduled, result: None }
+    /// Create a session for `participants`, each with its registered configuration.
+    pub fn new(id: GameId, participants: Vec<(BotId, BotConfig)>) -> Self {
+        let (participants, configs) = participants.into_iter().unzip();
+        Self {
+            id,
+            participants,
+            configs,
+            state: SessionState::Scheduled,
+            result: None,
+            record_replay: false,
+            lobby: None,
+        }
     }
 
-    pub async fn start(&mut self, _system_handle: &SystemHandle) -> Result<(), TournamentError> {
+    /// Play the match on a fresh engine and grid built from `config`.
+    ///
+    /// The game id is added to the seed so every game of a tournament gets
+    /// its own map and drops. Only the participants are spawned, remote ones
+    /// from a connection taken from the lobby, and the match runs until the
+    /// rules end it, held to [`MatchLimits::bounded`].
+    ///
+    /// [`MatchLimits::bounded`]: crate::MatchLimits::bounded
+    pub async fn play(&mut self, config: &EngineConfig) -> Result<(), TournamentError> {
+        if self.participants.is_empty() {
+            return Err(TournamentError::GameFailed("no participants".into()));
+        }
         self.state = SessionState::Running;
-        let winner = *self
-            .participants
-            .first()
-            .ok_or_else(|| TournamentError::GameFailed("no participants".into()))?;
-        self.result = Some(GameResult::new(self.participants.clone(), winner));
+
+        let mut config = config.clone();
+        config.seed = config.seed.wrapping_add(self.id as u64);
+        config.rules.limits = config.rules.limits.bounded();
+        let grid = config
+            .build_grid()
+            .map_err(|e| TournamentError::GameFailed(e.to_string()))?;
+        let events = Arc::new(EventBus::new());
+        let (mut engine, _rx) = Engine::with_components(
+            config.clone(),
+            Arc::new(RwLock::new(grid)),
+            Arc::clone(&events),
+        );
+        add_match_systems(&mut engine);
+        let filter = EventFilter::new(|e| {
+            matches!(
+                e,
+                Event::Game(GameEvent::AgentEliminated { .. })
+                    | Event::Bomb(BombEvent::CratesDestroyed { .. })
+                    | Event::Bomb(BombEvent::PowerUpCollected { .. })
+            )
+        });
+        let (_sub, rx) = events.subscribe_with_filter(Some(filter));
+
+        let mut participant_of = HashMap::new();
+        for (&participant, bot) in self.participants.iter().zip(&self.configs) {
+            let spawned = if bot.is_remote() {
+                self.spawn_remote(&mut engine, bot).await
+            } else {
+                engine
+                    .spawn_bot(bot.runtime_config())
+                    .map_err(|e| TournamentError::GameFailed(e.to_string()))
+            };
+            match spawned {
+                Ok(id) => {
+                    participant_of.insert(id, participant);
+                }
+                Err(e) => {
+                    engine.shutdown();
+                    return Err(e);
+                }
+            }
+        }
+
+        if self.record_replay {
+            engine.start_replay_recording();
+        }
+        let mut stats = MatchStats::default();
+        let outcome = loop {
+            if let Err(e) = engine.tick().await {
+                engine.shutdown();
+                return Err(TournamentError::GameFailed(e.to_string()));
+            }
+            for event in rx.try_iter() {
+                stats.record(event);
+            }
+            if let Some(outcome) = engine.check_game_end() {
+                break outcome;
+            }
+        };
+        engine.shutdown();
+
+        let ticks = engine.current_tick();
+        let tick_length = Duration::from_secs(1) / config.tick_rate.max(1);
+        let mut result = GameResult::new(
+            self.participants.clone(),
+            outcome.map_ids(|id| participant_of[&id]),
+        );
+        result.seed = config.seed;
+        if self.record_replay {
+            result.replay = Some(engine.stop_replay_recording());
+        }
+        for (&id, &participant) in &participant_of {
+            let survived = stats.eliminated_at.get(&id).copied().unwrap_or(ticks);
+            result
+                .survival_times
+                .insert(participant, tick_length * survived as u32);
+            result.destruction_points.insert(
+                participant,
+                stats.crates_destroyed.get(&id).copied().unwrap_or(0),
+            );
+            result.powerups_collected.insert(
+                participant,
+                stats.powerups_collected.get(&id).copied().unwrap_or(0),
+            );
+        }
+        self.result = Some(result);
         self.state = SessionState::Completed;
         Ok(())
     }
 
+    /// Spawn the remote bot `bot` on a connection taken from the lobby.
+    async fn spawn_remote(
+        &self,
+        engine: &mut Engine,
+        bot: &BotConfig,
+    ) -> Result<BotId, TournamentError> {
+        let lobby = self.lobby.clone().ok_or_else(|| {
+            TournamentError::GameFailed(format!(
+                "{} is a remote bot but remote bots are not allowed",
+                bot.name
+            ))
+        })?;
+        let name = bot.name.clone();
+        let connection = tokio::task::spawn_blocking(move || lobby.take(&name))
+            .await
+            .ok()
+            .flatten()
+            .ok_or_else(|| {
+                TournamentError::GameFailed(format!("remote bot {} did not connect", bot.name))
+            })?;
+        engine
+            .spawn_remote_bot(connection, Duration::from_millis(bot.decision_timeout_ms))
+            .map_err(|e| TournamentError::GameFailed(e.to_string()))
+    }
+
     pub async fn wait_for_completion(&mut self) -> Result<GameResult, TournamentError> {
         match self.result.clone() {
             Some(r) => Ok(r),
@@ -47,27 +218,65 @@ impl GameSession {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::SystemHandle;
+    use crate::MatchOutcome;
 
     #[test]
-    fn session_completes() {
+    fn session_plays_a_real_match() {
         let rt = tokio::runtime::Runtime::new().unwrap();
         rt.block_on(async {
-            let handle = dummy_handle();
-            let mut session = GameSession::new(0, vec![1, 2]);
-            session.start(&handle).await.unwrap();
+            let mut config = EngineConfig::default();
+            config.rules.limits.max_ticks = Some(10);
+            let mut session = GameSession::new(0, vec![(4, bot("a")), (7, bot("b"))]);
+            session.play(&config).await.unwrap();
+            assert_eq!(session.state, SessionState::Completed);
             let res = session.wait_for_completion().await.unwrap();
-            assert_eq!(res.winner, 1);
+            let mut ids = match res.outcome {
+                MatchOutcome::Winner(id) => vec![id],
+                MatchOutcome::Draw(ids) => ids,
+                MatchOutcome::Timeout { ranking } => ranking,
+            };
+            ids.sort_unstable();
+            assert!(ids.iter().all(|id| [4, 7].contains(id)), "{:?}", ids);
+            let mut timed: Vec<_> = res.survival_times.keys().copied().collect();
+            timed.sort_unstable();
+            assert_eq!(timed, vec![4, 7]);
+            assert!(res
+                .survival_times
+                .values()
+                .all(|t| *t <= Duration::from_secs(1) / 60 * 10));
+            assert_eq!(res.destruction_points.len(), 2);
+            assert_eq!(res.powerups_collected.len(), 2);
         });
     }
 
-    fn dummy_handle() -> SystemHandle {
-        use crate::{config::*, engine::Engine};
-        use events::bus::EventBus;
-        use std::sync::{Arc, RwLock};
-        let cfg = EngineConfig::default();
-        let grid = Arc::new(RwLock::new(state::GameGrid::new(5, 5)));
-        let (engine, _) = Engine::with_components(cfg, grid, Arc::new(EventBus::new()));
-        SystemHandle::new(Arc::new(EventBus::new()), engine, 0, None)
+    #[test]
+    fn stats_follow_match_events() {
+        let mut stats = MatchStats::default();
+        stats.record(Event::bomb(BombEvent::CratesDestroyed {
+            bomb_id: state::BombId(0),
+            agent_id: 1,
+            positions: vec![(1, 1), (3, 1)],
+        }));
+        stats.record(Event::bomb(BombEvent::PowerUpCollected {
+            agent_id: 1,
+            power_type: state::PowerUpType::Kick,
+        }));
+        stats.record(Event::Game(GameEvent::AgentEliminated {
+            entity_id: 0,
+            tick: 12,
+        }));
+        assert_eq!(stats.crates_destroyed[&1], 2);
+        assert_eq!(stats.powerups_collected[&1], 1);
+        assert_eq!(stats.eliminated_at[&0], 12);
+    }
+
+    fn bot(name: &str) -> BotConfig {
+        BotConfig {
+            name: name.into(),
+            ai_type: "Heuristic".into(),
+            rl_mode: false,
+            rl_model_path: None,
+            decision_timeout_ms: 10,
+        }
     }
 }
@@ -0,0 +1,105 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use log::debug;
+
+use crate::bot::remote::{RemoteBotListener, RemoteConnection};
+
+use super::registry::BotConnection;
+
+/// How often a match waiting for a remote bot looks for new connections.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Remote bots connected to a tournament, waiting for their next match.
+///
+/// A remote bot opens one connection per match. Every connection that
+/// completes its handshake waits here under the name the bot announced
+/// until a match the bot plays in takes it; a bot playing several matches
+/// at once opens one connection for each.
+pub struct RemoteLobby {
+    listener: RemoteBotListener,
+    waiting: Mutex<HashMap<String, VecDeque<RemoteConnection>>>,
+    /// How long a match waits for a participant to connect.
+    patience: Duration,
+}
+
+impl fmt::Debug for RemoteLobby {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteLobby")
+            .field("addr", &self.listener.local_addr().ok())
+            .field("patience", &self.patience)
+            .finish()
+    }
+}
+
+impl RemoteLobby {
+    /// Serve remote bots on `addr`; matches wait up to `patience` for a
+    /// participant to connect.
+    pub fn bind(addr: &str, patience: Duration) -> io::Result<Self> {
+        Ok(Self {
+            listener: RemoteBotListener::bind(addr)?,
+            waiting: Mutex::new(HashMap::new()),
+            patience,
+        })
+    }
+
+    /// Address remote bots connect to.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Queue the next bot to complete its handshake before `deadline`,
+    /// returning its name and connection details.
+    ///
+    /// Failed handshakes are logged and skipped.
+    pub fn admit(&self, deadline: Instant) -> Option<(String, BotConnection)> {
+        while let Some(handshake) = self.listener.accept_until(deadline) {
+            match handshake {
+                Ok(connection) => {
+                    let name = connection.name().to_string();
+                    let details = BotConnection {
+                        version: connection.version().to_string(),
+                        peer: connection.peer(),
+                    };
+                    self.waiting
+                        .lock()
+                        .expect("lobby lock poisoned")
+                        .entry(name.clone())
+                        .or_default()
+                        .push_back(connection);
+                    return Some((name, details));
+                }
+                Err(e) => debug!("remote bot handshake failed: {}", e),
+            }
+        }
+        None
+    }
+
+    /// Take a waiting connection of the bot called `name`, waiting for one
+    /// to arrive for as long as the lobby's patience allows.
+    pub fn take(&self, name: &str) -> Option<RemoteConnection> {
+        let deadline = Instant::now() + self.patience;
+        loop {
+            if let Some(connection) = self
+                .waiting
+                .lock()
+                .expect("lobby lock poisoned")
+                .get_mut(name)
+                .and_then(VecDeque::pop_front)
+            {
+                return Some(connection);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+            // Others may be waiting too; look for their bots' connections
+            // between checks of our own queue.
+            self.admit((now + POLL_INTERVAL).min(deadline));
+        }
+    }
+}
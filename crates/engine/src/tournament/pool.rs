@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use events::events::bot_events::BotId;
+use log::warn;
+use tokio::task::{self, JoinError, JoinSet};
+
+use crate::config::{EngineConfig, UnifiedBotConfig as BotConfig};
+
+use super::game_session::GameSession;
+use super::lobby::RemoteLobby;
+use super::scheduler::GameId;
+use super::{GameResult, TournamentError};
+
+/// A match waiting to be played: its id and its participants with their configurations.
+pub type PendingMatch = (GameId, Vec<(BotId, BotConfig)>);
+
+/// What a match task hands back once it finishes.
+type PlayedMatch = (GameId, Result<GameResult, TournamentError>);
+
+/// Runs matches in parallel on the Tokio runtime, each on its own engine.
+///
+/// At most `max_concurrent` matches run at once. A match still running after
+/// `timeout` is cancelled, which drops its engine and stops its bots.
+#[derive(Debug, Clone)]
+pub struct MatchPool {
+    max_concurrent: usize,
+    timeout: Duration,
+    record_replays: bool,
+    lobby: Option<Arc<RemoteLobby>>,
+}
+
+impl MatchPool {
+    pub fn new(max_concurrent: usize, timeout: Duration) -> Self {
+        Self {
+            max_concurrent: max_concurrent.max(1),
+            timeout,
+            record_replays: false,
+            lobby: None,
+        }
+    }
+
+    /// Keep a replay of every match in its result.
+    pub fn recording_replays(mut self, record: bool) -> Self {
+        self.record_replays = record;
+        self
+    }
+
+    /// Take remote participants' connections from `lobby`.
+    pub fn with_lobby(mut self, lobby: Option<Arc<RemoteLobby>>) -> Self {
+        self.lobby = lobby;
+        self
+    }
+
+    /// Play `matches` with the engine configuration `config`.
+    ///
+    /// Every match yields its own result, in ascending game id order; a match
+    /// that fails, panics or times out does not affect the others.
+    /// `on_played` sees every successful match as soon as it finishes.
+    pub async fn run(
+        &self,
+        config: &EngineConfig,
+        matches: Vec<PendingMatch>,
+        mut on_played: impl FnMut(GameId, &GameResult),
+    ) -> Vec<(GameId, Result<GameResult, TournamentError>)> {
+        let mut pending = matches.into_iter();
+        let mut running = JoinSet::new();
+        // Game of every running task, for tasks that end without a result.
+        let mut games = HashMap::new();
+        let mut results = Vec::new();
+        loop {
+            while running.len() < self.max_concurrent {
+                let Some((id, participants)) = pending.next() else {
+                    break;
+                };
+                let config = config.clone();
+                let timeout = self.timeout;
+                let record_replay = self.record_replays;
+                let lobby = self.lobby.clone();
+                let handle = running.spawn(async move {
+                    let mut session = GameSession::new(id, participants);
+                    session.record_replay = record_replay;
+                    session.lobby = lobby;
+                    let played = match tokio::time::timeout(timeout, session.play(&config)).await {
+                        Ok(played) => played,
+                        Err(_) => Err(TournamentError::GameTimedOut(id)),
+                    };
+                    let result = match played {
+                        Ok(()) => session.wait_for_completion().await,
+                        Err(e) => Err(e),
+                    };
+                    (id, result)
+                });
+                games.insert(handle.id(), id);
+            }
+            let Some(joined) = running.join_next_with_id().await else {
+                break;
+            };
+            let (id, result) = settle(&mut games, joined);
+            match &result {
+                Ok(result) => on_played(id, result),
+                Err(e) => warn!("game {} failed: {}", id, e),
+            }
+            results.push((id, result));
+        }
+        results.sort_by_key(|(id, _)| *id);
+        results
+    }
+}
+
+/// The game played by a finished task and its result; a task that panicked
+/// or was cancelled counts as a failed game.
+fn settle(
+    games: &mut HashMap<task::Id, GameId>,
+    joined: Result<(task::Id, PlayedMatch), JoinError>,
+) -> PlayedMatch {
+    match joined {
+        Ok((task, played)) => {
+            games.remove(&task);
+            played
+        }
+        Err(e) => {
+            let id = games.remove(&e.id()).expect("every match task is tracked");
+            let reason = if e.is_panic() {
+                "match panicked"
+            } else {
+                "match was cancelled"
+            };
+            (id, Err(TournamentError::GameFailed(reason.into())))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bot(name: &str) -> BotConfig {
+        BotConfig {
+            name: name.into(),
+            ai_type: "Heuristic".into(),
+            rl_mode: false,
+            rl_model_path: None,
+            decision_timeout_ms: 10,
+        }
+    }
+
+    fn pair(id: GameId) -> PendingMatch {
+        (id, vec![(0, bot("a")), (1, bot("b"))])
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn plays_matches_and_keeps_going_past_failures() {
+        let mut config = EngineConfig::default();
+        config.rules.limits.max_ticks = Some(10);
+        let pool = MatchPool::new(2, Duration::from_secs(30));
+        let mut played = Vec::new();
+        let results = pool
+            .run(
+                &config,
+                vec![pair(2), (1, Vec::new()), pair(0), pair(3)],
+                |id, _| played.push(id),
+            )
+            .await;
+        played.sort_unstable();
+        assert_eq!(played, vec![0, 2, 3]);
+        let ids: Vec<GameId> = results.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![0, 1, 2, 3]);
+        assert!(matches!(results[1].1, Err(TournamentError::GameFailed(_))));
+        for (_, result) in results.iter().filter(|(id, _)| *id != 1) {
+            assert_eq!(result.as_ref().unwrap().participants, vec![0, 1]);
+        }
+    }
+
+    #[tokio::test]
+    async fn panicked_matches_keep_their_game_id() {
+        let mut running = JoinSet::new();
+        let mut games = HashMap::new();
+        let handle = running.spawn(async { panic!("engine bug") });
+        games.insert(handle.id(), 7);
+        let joined = running.join_next_with_id().await.unwrap();
+        let (id, result) = settle(&mut games, joined);
+        assert_eq!(id, 7);
+        assert!(matches!(result, Err(TournamentError::GameFailed(_))));
+        assert!(games.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn long_matches_are_cancelled() {
+        let mut config = EngineConfig::default();
+        config.rules.limits.max_ticks = Some(u64::MAX);
+        let pool = MatchPool::new(1, Duration::from_millis(50));
+        let results = pool.run(&config, vec![pair(0)], |_, _| {}).await;
+        assert!(matches!(
+            results[0].1,
+            Err(TournamentError::GameTimedOut(0))
+        ));
+    }
+}
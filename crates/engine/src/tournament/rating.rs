@@ -0,0 +1,145 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use events::events::bot_events::BotId;
+use serde::{Deserialize, Serialize};
+
+use super::{GameResult, TournamentError};
+
+/// Rating of a bot that has not played yet.
+pub const INITIAL_RATING: f64 = 1500.0;
+
+/// Largest rating change a single game can cause.
+pub const K_FACTOR: f64 = 32.0;
+
+/// Elo rating of one bot.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Rating {
+    pub rating: f64,
+    pub games: u32,
+}
+
+impl Default for Rating {
+    fn default() -> Self {
+        Self {
+            rating: INITIAL_RATING,
+            games: 0,
+        }
+    }
+}
+
+/// Elo ratings keyed by bot name, kept across tournaments in a JSON file.
+///
+/// A game between several bots counts as a match between every pair of
+/// them, scored by their placements, with the K-factor shared among a bot's
+/// opponents so a free-for-all moves a rating as much as a duel. Bots
+/// registered under the same name share a rating.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RatingBook {
+    pub ratings: BTreeMap<String, Rating>,
+}
+
+impl RatingBook {
+    /// Read the book at `path`; a missing file is an empty book.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, TournamentError> {
+        match std::fs::read_to_string(path) {
+            Ok(data) => Ok(serde_json::from_str(&data)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), TournamentError> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Rating of the bot called `name`.
+    pub fn rating(&self, name: &str) -> f64 {
+        self.ratings.get(name).map_or(INITIAL_RATING, |r| r.rating)
+    }
+
+    /// Update the ratings from `result`, whose participants are named by `names`.
+    pub fn record(&mut self, result: &GameResult, names: &HashMap<BotId, String>) {
+        let placements: Vec<(&String, u32)> = result
+            .placements()
+            .into_iter()
+            .filter_map(|(bot, place)| names.get(&bot).map(|name| (name, place)))
+            .collect();
+        if placements.len() < 2 {
+            return;
+        }
+        let k = K_FACTOR / (placements.len() - 1) as f64;
+        let changes: Vec<f64> = placements
+            .iter()
+            .map(|&(name, place)| {
+                let own = self.rating(name);
+                placements
+                    .iter()
+                    .filter(|(other, _)| *other != name)
+                    .map(|&(other, other_place)| {
+                        let expected = 1.0 / (1.0 + 10f64.powf((self.rating(other) - own) / 400.0));
+                        let actual = match place.cmp(&other_place) {
+                            std::cmp::Ordering::Less => 1.0,
+                            std::cmp::Ordering::Equal => 0.5,
+                            std::cmp::Ordering::Greater => 0.0,
+                        };
+                        k * (actual - expected)
+                    })
+                    .sum()
+            })
+            .collect();
+        for (&(name, _), change) in placements.iter().zip(changes) {
+            let entry = self.ratings.entry(name.clone()).or_default();
+            entry.rating += change;
+            entry.games += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MatchOutcome;
+
+    fn names(n: usize) -> HashMap<BotId, String> {
+        (0..n).map(|id| (id, format!("bot{}", id))).collect()
+    }
+
+    #[test]
+    fn winners_gain_what_losers_lose() {
+        let mut book = RatingBook::default();
+        book.record(
+            &GameResult::new(vec![0, 1], MatchOutcome::Winner(0)),
+            &names(2),
+        );
+        assert_eq!(book.rating("bot0"), INITIAL_RATING + K_FACTOR / 2.0);
+        assert_eq!(book.rating("bot1"), INITIAL_RATING - K_FACTOR / 2.0);
+        assert_eq!(book.ratings["bot0"].games, 1);
+
+        let ffa = GameResult::new(
+            vec![0, 1, 2],
+            MatchOutcome::Timeout {
+                ranking: vec![2, 0, 1],
+            },
+        );
+        book.record(&ffa, &names(3));
+        let total: f64 = book.ratings.values().map(|r| r.rating).sum();
+        assert!((total - 3.0 * INITIAL_RATING).abs() < 1e-9);
+        assert!(book.rating("bot2") > INITIAL_RATING);
+    }
+
+    #[test]
+    fn books_survive_a_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ratings.json");
+        assert_eq!(RatingBook::load(&path).unwrap(), RatingBook::default());
+        let mut book = RatingBook::default();
+        book.record(
+            &GameResult::new(vec![0, 1], MatchOutcome::Draw(vec![0, 1])),
+            &names(2),
+        );
+        book.save(&path).unwrap();
+        assert_eq!(RatingBook::load(&path).unwrap(), book);
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::net::SocketAddr;
 
 use events::events::bot_events::BotId;
 
@@ -25,7 +26,8 @@ pub struct RegisteredBot {
     pub _id: BotId,
     pub _config: BotConfig,
     pub _status: BotStatus,
-    pub _connection: Option<BotConnection>,
+    /// How a remote bot connected when it registered; `None` for local bots.
+    pub connection: Option<BotConnection>,
 }
 
 #[derive(Debug, Clone)]
@@ -38,18 +40,39 @@ pub enum BotStatus {
     Banned,
 }
 
-#[derive(Debug, Clone)]
-pub struct BotConnection;
+/// Details a remote bot announced in its handshake.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BotConnection {
+    pub version: String,
+    pub peer: SocketAddr,
+}
 
 impl BotRegistry {
     pub fn register_bot(&mut self, config: BotConfig) -> Result<BotId, TournamentError> {
+        self.register(config, None)
+    }
+
+    /// Register a bot that connected over the remote bot protocol.
+    pub fn register_remote_bot(
+        &mut self,
+        config: BotConfig,
+        connection: BotConnection,
+    ) -> Result<BotId, TournamentError> {
+        self.register(config, Some(connection))
+    }
+
+    fn register(
+        &mut self,
+        config: BotConfig,
+        connection: Option<BotConnection>,
+    ) -> Result<BotId, TournamentError> {
         let id = self.next_id;
         self.next_id += 1;
         let bot = RegisteredBot {
             _id: id,
             _config: config,
             _status: BotStatus::Registered,
-            _connection: None,
+            connection,
         };
         self.bots.insert(id, bot);
         Ok(id)
@@ -63,8 +86,19 @@ impl BotRegistry {
             .collect()
     }
 
+    pub fn config(&self, id: BotId) -> Option<&BotConfig> {
+        self.bots.get(&id).map(|b| &b._config)
+    }
+
+    pub fn connection(&self, id: BotId) -> Option<&BotConnection> {
+        self.bots.get(&id).and_then(|b| b.connection.as_ref())
+    }
+
+    /// Registered bot ids in registration order.
     pub fn get_bot_ids(&self) -> Vec<BotId> {
-        self.bots.keys().cloned().collect()
+        let mut ids: Vec<BotId> = self.bots.keys().cloned().collect();
+        ids.sort_unstable();
+        ids
     }
 }
 
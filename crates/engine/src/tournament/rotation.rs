@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use events::events::bot_events::BotId;
+
+use super::scheduler::{GameId, GameMatch};
+
+/// Free-for-all rotation.
+///
+/// Every round splits the field into matches of at most `players_per_match`
+/// bots, as even in size as possible. Bots are grouped with the opponents
+/// they have met least so far, and within a match each spawn position goes to
+/// the bot that has started there least often; the engine hands out spawn
+/// points in participant order.
+#[derive(Debug, Clone)]
+pub struct FreeForAllRotation {
+    players_per_match: usize,
+    meetings: HashMap<(BotId, BotId), u32>,
+    spawn_slots: HashMap<(BotId, usize), u32>,
+}
+
+fn pair_key(a: BotId, b: BotId) -> (BotId, BotId) {
+    (a.min(b), a.max(b))
+}
+
+impl FreeForAllRotation {
+    pub fn new(players_per_match: usize) -> Self {
+        Self {
+            players_per_match: players_per_match.max(2),
+            meetings: HashMap::new(),
+            spawn_slots: HashMap::new(),
+        }
+    }
+
+    /// How many matches `a` and `b` have been scheduled into together.
+    pub fn meetings(&self, a: BotId, b: BotId) -> u32 {
+        self.meetings.get(&pair_key(a, b)).copied().unwrap_or(0)
+    }
+
+    /// How many times `bot` has been scheduled to start at spawn `slot`.
+    pub fn spawn_count(&self, bot: BotId, slot: usize) -> u32 {
+        self.spawn_slots.get(&(bot, slot)).copied().unwrap_or(0)
+    }
+
+    /// Schedule one round for `bots`, numbering the games from `next_id`.
+    pub fn schedule(&mut self, bots: &[BotId], next_id: &mut GameId) -> Vec<GameMatch> {
+        if bots.len() < 2 {
+            return Vec::new();
+        }
+        let groups = bots.len().div_ceil(self.players_per_match);
+        let mut unassigned = bots.to_vec();
+        let mut games = Vec::with_capacity(groups);
+        for g in 0..groups {
+            let size = unassigned.len() / (groups - g);
+            let mut group = vec![unassigned.remove(0)];
+            while group.len() < size {
+                let (pos, _) = unassigned
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, &bot)| {
+                        group.iter().map(|&m| self.meetings(bot, m)).sum::<u32>()
+                    })
+                    .expect("enough bots left for the group");
+                group.push(unassigned.remove(pos));
+            }
+            let participants = self.assign_spawns(group);
+            for (i, &a) in participants.iter().enumerate() {
+                for &b in &participants[i + 1..] {
+                    *self.meetings.entry(pair_key(a, b)).or_default() += 1;
+                }
+            }
+            games.push(GameMatch {
+                id: *next_id,
+                participants,
+            });
+            *next_id += 1;
+        }
+        games
+    }
+
+    /// Order `group` by spawn position, giving each slot in turn to the bot
+    /// that has used it least.
+    fn assign_spawns(&mut self, mut group: Vec<BotId>) -> Vec<BotId> {
+        let mut order = Vec::with_capacity(group.len());
+        for slot in 0..group.len() {
+            let (pos, _) = group
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, &bot)| self.spawn_count(bot, slot))
+                .expect("a bot left for every slot");
+            let bot = group.remove(pos);
+            *self.spawn_slots.entry((bot, slot)).or_default() += 1;
+            order.push(bot);
+        }
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_the_field_into_even_matches() {
+        let mut rotation = FreeForAllRotation::new(4);
+        let mut next_id = 0;
+        let bots: Vec<BotId> = (0..10).collect();
+        let games = rotation.schedule(&bots, &mut next_id);
+        let sizes: Vec<usize> = games.iter().map(|g| g.participants.len()).collect();
+        assert_eq!(sizes, vec![3, 3, 4]);
+        let mut all: Vec<BotId> = games.iter().flat_map(|g| g.participants.clone()).collect();
+        all.sort_unstable();
+        assert_eq!(all, bots);
+        assert_eq!(next_id, 3);
+    }
+
+    #[test]
+    fn rotations_balance_meetings_and_spawns() {
+        let mut rotation = FreeForAllRotation::new(2);
+        let mut next_id = 0;
+        let bots = [0, 1, 2, 3];
+        for _ in 0..3 {
+            rotation.schedule(&bots, &mut next_id);
+        }
+        for a in bots {
+            for b in bots.into_iter().filter(|&b| b != a) {
+                assert_eq!(rotation.meetings(a, b), 1, "{} and {}", a, b);
+            }
+        }
+
+        let mut rotation = FreeForAllRotation::new(8);
+        for _ in 0..4 {
+            rotation.schedule(&bots, &mut next_id);
+        }
+        for bot in bots {
+            assert!((0..4).all(|slot| rotation.spawn_count(bot, slot) == 1));
+        }
+    }
+}
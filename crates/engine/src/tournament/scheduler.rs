@@ -1,5 +1,13 @@
-use crate::config::TournamentFormat;
+use std::collections::HashMap;
+
+use crate::config::{Seeding, TournamentFormat};
 use events::events::bot_events::BotId;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use super::bracket::Bracket;
+use super::rotation::FreeForAllRotation;
+use super::swiss::SwissPairing;
+use super::GameResult;
 
 pub type GameId = usize;
 
@@ -13,6 +21,12 @@ pub struct GameMatch {
 pub struct GameScheduler {
     pub format: TournamentFormat,
     pub current_round: u32,
+    bracket: Option<Bracket>,
+    swiss: SwissPairing,
+    bye: Option<BotId>,
+    rotation: Option<FreeForAllRotation>,
+    next_game_id: GameId,
+    ratings: HashMap<BotId, f64>,
 }
 
 impl GameScheduler {
@@ -20,17 +34,49 @@ impl GameScheduler {
         Self {
             format,
             current_round: 0,
+            bracket: None,
+            swiss: SwissPairing::default(),
+            bye: None,
+            rotation: None,
+            next_game_id: 0,
+            ratings: HashMap::new(),
+        }
+    }
+
+    /// Prior ratings used by [`Seeding::Rating`].
+    pub fn set_ratings(&mut self, ratings: HashMap<BotId, f64>) {
+        self.ratings = ratings;
+    }
+
+    /// The elimination bracket, once its first round has been scheduled.
+    pub fn bracket(&self) -> Option<&Bracket> {
+        self.bracket.as_ref()
+    }
+
+    /// The bot sitting out the round scheduled last, in a Swiss tournament.
+    pub fn bye(&self) -> Option<BotId> {
+        self.bye
+    }
+
+    /// Feed the results of the last round back into the schedule.
+    pub fn record_results(&mut self, results: &[(GameId, GameResult)]) {
+        for (id, result) in results {
+            if let Some(bracket) = &mut self.bracket {
+                bracket.record(*id, result);
+            }
+            self.swiss.record(result);
         }
     }
 
     pub fn has_next_round(&self) -> bool {
         match self.format {
             TournamentFormat::RoundRobin { total_rounds } => self.current_round < total_rounds,
-            TournamentFormat::SingleElimination { bracket_size } => {
-                let rounds = bracket_size.next_power_of_two().trailing_zeros();
-                self.current_round < rounds
-            }
+            TournamentFormat::SingleElimination { .. } => match &self.bracket {
+                Some(bracket) => !bracket.is_finished(),
+                None => true,
+            },
             TournamentFormat::Swiss { rounds } => self.current_round < rounds,
+            TournamentFormat::FreeForAll { rounds, .. } => self.current_round < rounds,
         }
     }
 
@@ -38,9 +84,51 @@ impl GameScheduler {
         self.current_round += 1;
         match self.format {
             TournamentFormat::RoundRobin { .. } => self.generate_round_robin(bots),
-            TournamentFormat::SingleElimination { .. } => self.generate_round_robin(bots),
-            TournamentFormat::Swiss { .. } => self.generate_round_robin(bots),
+            TournamentFormat::SingleElimination { .. } => self.generate_elimination(bots),
+            TournamentFormat::Swiss { .. } => {
+                let (games, bye) = self.swiss.pair(bots, &mut self.next_game_id);
+                self.bye = bye;
+                games
+            }
+            TournamentFormat::FreeForAll {
+                players_per_match, ..
+            } => {
+                let rotation = self
+                    .rotation
+                    .get_or_insert_with(|| FreeForAllRotation::new(players_per_match as usize));
+                rotation.schedule(bots, &mut self.next_game_id)
+            }
+        }
+    }
+
+    fn generate_elimination(&mut self, bots: &[BotId]) -> Vec<GameMatch> {
+        if self.bracket.is_none() {
+            let TournamentFormat::SingleElimination {
+                bracket_size,
+                seeding,
+            } = &self.format
+            else {
+                return Vec::new();
+            };
+            let seeds = self.seed_order(bots, seeding);
+            self.bracket = Some(Bracket::new(seeds, *bracket_size as usize));
         }
+        let bracket = self.bracket.as_mut().expect("bracket was just built");
+        bracket.schedule(&mut self.next_game_id)
+    }
+
+    /// `bots`, given in registration order, sorted best seed first.
+    fn seed_order(&self, bots: &[BotId], seeding: &Seeding) -> Vec<BotId> {
+        let mut seeds = bots.to_vec();
+        match seeding {
+            Seeding::RegistrationOrder => {}
+            Seeding::Random { seed } => seeds.shuffle(&mut StdRng::seed_from_u64(*seed)),
+            Seeding::Rating => seeds.sort_by(|a, b| {
+                let rating = |bot| self.ratings.get(bot).copied().unwrap_or(f64::NEG_INFINITY);
+                rating(b).total_cmp(&rating(a))
+            }),
+        }
+        seeds
     }
 
     fn generate_round_robin(&self, bots: &[BotId]) -> Vec<GameMatch> {
@@ -73,4 +161,61 @@ mod tests {
         let m0 = &matches[0];
         assert_eq!(m0.participants.len(), 2);
     }
+
+    #[test]
+    fn swiss_rounds_avoid_rematches() {
+        let mut sched = GameScheduler::new(TournamentFormat::Swiss { rounds: 2 });
+        let bots = vec![0, 1, 2];
+        let first = sched.schedule_next_round(&bots);
+        assert_eq!(first[0].participants, vec![0, 1]);
+        assert_eq!(sched.bye(), Some(2));
+        let won = GameResult::new(vec![0, 1], crate::MatchOutcome::Winner(0));
+        sched.record_results(&[(first[0].id, won)]);
+
+        let second = sched.schedule_next_round(&bots);
+        assert_eq!(second[0].participants, vec![0, 2]);
+        assert_eq!(sched.bye(), Some(1));
+        assert!(!sched.has_next_round());
+    }
+
+    #[test]
+    fn free_for_all_rounds_split_the_field() {
+        let mut sched = GameScheduler::new(TournamentFormat::FreeForAll {
+            rounds: 2,
+            players_per_match: 8,
+        });
+        let bots: Vec<BotId> = (0..8).collect();
+        let first = sched.schedule_next_round(&bots);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].participants, bots);
+        let second = sched.schedule_next_round(&bots);
+        assert_eq!(second[0].id, 1);
+        assert_eq!(second[0].participants[0], 1);
+        assert!(!sched.has_next_round());
+    }
+
+    #[test]
+    fn elimination_follows_the_bracket() {
+        let mut sched = GameScheduler::new(TournamentFormat::SingleElimination {
+            bracket_size: 4,
+            seeding: Seeding::Rating,
+        });
+        sched.set_ratings(HashMap::from([(2, 1800.0), (0, 1500.0)]));
+        let bots = vec![0, 1, 2];
+        assert!(sched.has_next_round());
+        let first = sched.schedule_next_round(&bots);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].participants, vec![0, 1]);
+        let won = GameResult::new(vec![0, 1], crate::MatchOutcome::Winner(1));
+        sched.record_results(&[(first[0].id, won)]);
+        assert!(sched.has_next_round());
+
+        let last = sched.schedule_next_round(&bots);
+        assert_eq!(last[0].participants, vec![2, 1]);
+        assert_ne!(last[0].id, first[0].id);
+        sched.record_results(&[]);
+        assert!(sched.schedule_next_round(&bots).is_empty());
+        assert_eq!(sched.bracket().unwrap().champion(), Some(2));
+        assert!(!sched.has_next_round());
+    }
 }
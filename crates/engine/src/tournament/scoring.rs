@@ -2,30 +2,129 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use events::events::bot_events::BotId;
+use serde::{Deserialize, Serialize};
 
 use super::GameResult;
 use crate::config::ScoringSystem;
+use crate::MatchOutcome;
 
-#[derive(Debug, Clone, Default)]
+/// Game points each participant earns from `result`: 1 for the winner, a
+/// half for every bot sharing a draw or playing a game that timed out, and
+/// nothing otherwise.
+pub(super) fn match_points(result: &GameResult) -> Vec<(BotId, f32)> {
+    result
+        .participants
+        .iter()
+        .map(|&bot| {
+            let points = match &result.outcome {
+                MatchOutcome::Winner(id) if *id == bot => 1.0,
+                MatchOutcome::Draw(ids) if ids.contains(&bot) => 0.5,
+                MatchOutcome::Timeout { .. } => 0.5,
+                _ => 0.0,
+            };
+            (bot, points)
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BotScore {
     pub wins: u32,
+    pub draws: u32,
     pub losses: u32,
+    /// Rounds sat out with a bye, each worth a win.
+    pub byes: u32,
     pub survival_time_total: Duration,
     pub destruction_points: u32,
     pub powerups_collected: u32,
+    /// Finishing place in every game played, counting from 1.
+    pub placements: Vec<u32>,
+    /// Points earned from placements under [`ScoringSystem::Placement`].
+    pub placement_points: u32,
+    /// Opponents that finished below the bot, summed over its games.
+    pub opponents_outlasted: u32,
+    /// Every opponent faced, with the game points earned against it.
+    pub opponents: Vec<(BotId, f32)>,
+    /// Sum of the opponents' game points; filled in by [`ScoreTracker::get_rankings`].
+    pub buchholz: f32,
+    /// Sum of the opponents' game points weighted by the result against each;
+    /// filled in by [`ScoreTracker::get_rankings`].
+    pub sonneborn_berger: f32,
+}
+
+impl BotScore {
+    /// Game points: 1 per win or bye and a half per draw.
+    pub fn points(&self) -> f32 {
+        (self.wins + self.byes) as f32 + self.draws as f32 * 0.5
+    }
+}
+
+/// A bot's standing in the tournament.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ranking {
+    pub bot: BotId,
+    pub score: BotScore,
+    /// Points under the tournament's [`ScoringSystem`].
+    pub points: f64,
+    /// Place in the standings, counting from 1.
+    pub rank: u32,
+}
+
+/// Tournament points `score` is worth under `system`.
+fn total_points(system: &ScoringSystem, score: &BotScore) -> f64 {
+    match system {
+        ScoringSystem::WinLoss {
+            win_points,
+            loss_points,
+        } => {
+            let (win, loss) = (*win_points as f64, *loss_points as f64);
+            (score.wins + score.byes) as f64 * win
+                + score.losses as f64 * loss
+                + score.draws as f64 * (win + loss) / 2.0
+        }
+        ScoringSystem::Survival { time_multiplier } => {
+            score.survival_time_total.as_secs_f64() * *time_multiplier as f64
+        }
+        ScoringSystem::Destruction {
+            crate_points,
+            enemy_points,
+        } => {
+            score.destruction_points as f64 * *crate_points as f64
+                + score.opponents_outlasted as f64 * *enemy_points as f64
+        }
+        ScoringSystem::Hybrid { weights } => weights
+            .iter()
+            .map(|(stat, weight)| {
+                let value = match stat.as_str() {
+                    "wins" => score.wins as f64,
+                    "draws" => score.draws as f64,
+                    "losses" => score.losses as f64,
+                    "byes" => score.byes as f64,
+                    "survival" => score.survival_time_total.as_secs_f64(),
+                    "crates" => score.destruction_points as f64,
+                    "powerups" => score.powerups_collected as f64,
+                    "outlasted" => score.opponents_outlasted as f64,
+                    // Rejected by `UnifiedConfig::validate`.
+                    _ => 0.0,
+                };
+                value * *weight as f64
+            })
+            .sum(),
+        ScoringSystem::Placement { .. } => score.placement_points as f64,
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct ScoreTracker {
     pub bot_scores: HashMap<BotId, BotScore>,
-    pub _scoring_system: ScoringSystem,
+    pub scoring_system: ScoringSystem,
 }
 
 impl ScoreTracker {
     pub fn new(scoring_system: ScoringSystem) -> Self {
         Self {
             bot_scores: HashMap::new(),
-            _scoring_system: scoring_system,
+            scoring_system,
         }
     }
 
@@ -35,14 +134,38 @@ impl ScoreTracker {
         }
     }
 
+    /// Credit `bot` with a bye.
+    pub fn record_bye(&mut self, bot: BotId) {
+        self.bot_scores.entry(bot).or_default().byes += 1;
+    }
+
     fn update_individual_scores(&mut self, result: &GameResult) {
-        for &bot in &result.participants {
+        let placements = result.placements();
+        for &(bot, place) in &placements {
             let entry = self.bot_scores.entry(bot).or_default();
-            if bot == result.winner {
+            entry.placements.push(place);
+            entry.opponents_outlasted +=
+                placements.iter().filter(|(_, p)| *p > place).count() as u32;
+            if let ScoringSystem::Placement { points } = &self.scoring_system {
+                entry.placement_points += points.get(place as usize - 1).copied().unwrap_or(0);
+            }
+        }
+        let points = match_points(result);
+        for &(bot, earned) in &points {
+            let entry = self.bot_scores.entry(bot).or_default();
+            if earned == 1.0 {
                 entry.wins += 1;
+            } else if earned > 0.0 {
+                entry.draws += 1;
             } else {
                 entry.losses += 1;
             }
+            entry.opponents.extend(
+                points
+                    .iter()
+                    .filter(|(other, _)| *other != bot)
+                    .map(|&(other, _)| (other, earned)),
+            );
             entry.survival_time_total +=
                 result.survival_times.get(&bot).copied().unwrap_or_default();
             entry.destruction_points += result.destruction_points.get(&bot).copied().unwrap_or(0);
@@ -50,18 +173,45 @@ impl ScoreTracker {
         }
     }
 
-    pub fn get_rankings(&self) -> Vec<(BotId, BotScore, u32)> {
-        let mut scores: Vec<(BotId, BotScore)> = self
+    /// Bots ordered by their points under the scoring system, with ties
+    /// broken as documented on [`ScoringSystem`].
+    pub fn get_rankings(&self) -> Vec<Ranking> {
+        let game_points = |bot: &BotId| self.bot_scores.get(bot).map_or(0.0, BotScore::points);
+        let mut rankings: Vec<Ranking> = self
             .bot_scores
             .iter()
-            .map(|(id, score)| (*id, score.clone()))
+            .map(|(id, score)| {
+                let mut score = score.clone();
+                score.buchholz = score.opponents.iter().map(|(o, _)| game_points(o)).sum();
+                score.sonneborn_berger = score
+                    .opponents
+                    .iter()
+                    .map(|(o, r)| r * game_points(o))
+                    .sum();
+                Ranking {
+                    bot: *id,
+                    points: total_points(&self.scoring_system, &score),
+                    score,
+                    rank: 0,
+                }
+            })
             .collect();
-        scores.sort_by(|a, b| b.1.wins.cmp(&a.1.wins));
-        scores
-            .into_iter()
-            .enumerate()
-            .map(|(idx, (id, score))| (id, score, idx as u32 + 1))
-            .collect()
+        rankings.sort_by(|a, b| {
+            b.points
+                .total_cmp(&a.points)
+                .then(b.score.points().total_cmp(&a.score.points()))
+                .then(b.score.buchholz.total_cmp(&a.score.buchholz))
+                .then(
+                    b.score
+                        .sonneborn_berger
+                        .total_cmp(&a.score.sonneborn_berger),
+                )
+                .then(a.bot.cmp(&b.bot))
+        });
+        for (idx, ranking) in rankings.iter_mut().enumerate() {
+            ranking.rank = idx as u32 + 1;
+        }
+        rankings
     }
 }
 
@@ -72,7 +222,7 @@ mod tests {
 
     fn sample_result(winner: BotId, loser: BotId) -> GameResult {
         GameResult {
-            winner,
+            outcome: MatchOutcome::Winner(winner),
             participants: vec![winner, loser],
             survival_times: HashMap::from([
                 (winner, Duration::from_secs(10)),
@@ -80,6 +230,8 @@ mod tests {
             ]),
             destruction_points: HashMap::new(),
             powerups_collected: HashMap::new(),
+            seed: 0,
+            replay: None,
         }
     }
 
@@ -94,6 +246,100 @@ mod tests {
         tracker.update_scores(&[r1, r2]);
         let rankings = tracker.get_rankings();
         assert_eq!(rankings.len(), 2);
-        assert_eq!(rankings[0].1.wins, 1);
+        assert_eq!(rankings[0].score.wins, 1);
+        assert_eq!(rankings[0].points, 1.0);
+    }
+
+    #[test]
+    fn ties_are_broken_by_buchholz_then_sonneborn_berger() {
+        let mut tracker = ScoreTracker::new(ScoringSystem::WinLoss {
+            win_points: 1,
+            loss_points: 0,
+        });
+        tracker.update_scores(&[
+            sample_result(4, 3),
+            sample_result(2, 1),
+            sample_result(3, 1),
+        ]);
+        let rankings = tracker.get_rankings();
+        let order: Vec<BotId> = rankings.iter().map(|r| r.bot).collect();
+        // 4, 3 and 2 share a point: 2 met the weakest opponent, and 4 beat 3.
+        assert_eq!(order, vec![4, 3, 2, 1]);
+        assert_eq!(rankings[1].score.buchholz, 1.0);
+        assert_eq!(rankings[2].score.buchholz, 0.0);
+        assert_eq!(rankings[0].score.sonneborn_berger, 1.0);
+        assert_eq!(rankings[1].score.sonneborn_berger, 0.0);
+        assert_eq!(rankings[3].rank, 4);
+
+        let score = BotScore {
+            wins: 1,
+            draws: 1,
+            byes: 1,
+            ..BotScore::default()
+        };
+        assert_eq!(score.points(), 2.5);
+    }
+
+    #[test]
+    fn placements_earn_points() {
+        let mut tracker = ScoreTracker::new(ScoringSystem::Placement {
+            points: vec![5, 3, 1],
+        });
+        let mut first = GameResult::new(vec![0, 1, 2, 3], MatchOutcome::Winner(3));
+        first.survival_times = HashMap::from([
+            (0, Duration::from_secs(4)),
+            (1, Duration::from_secs(9)),
+            (2, Duration::from_secs(4)),
+        ]);
+        assert_eq!(first.placements(), vec![(3, 1), (1, 2), (0, 3), (2, 3)]);
+        let second = GameResult::new(
+            vec![0, 1, 2, 3],
+            MatchOutcome::Timeout {
+                ranking: vec![0, 2, 1, 3],
+            },
+        );
+        tracker.update_scores(&[first, second]);
+
+        let rankings = tracker.get_rankings();
+        let points: Vec<(BotId, f64)> = rankings.iter().map(|r| (r.bot, r.points)).collect();
+        assert_eq!(points, vec![(0, 6.0), (3, 5.0), (1, 4.0), (2, 4.0)]);
+        assert_eq!(rankings[0].score.placements, vec![3, 1]);
+    }
+
+    #[test]
+    fn every_scoring_system_totals_points() {
+        let mut result = sample_result(1, 2);
+        result.destruction_points = HashMap::from([(1, 1), (2, 4)]);
+        result.powerups_collected = HashMap::from([(2, 3)]);
+        let points = |system: ScoringSystem| {
+            let mut tracker = ScoreTracker::new(system);
+            tracker.update_scores(&[result.clone()]);
+            tracker
+                .get_rankings()
+                .iter()
+                .map(|r| (r.bot, r.points))
+                .collect::<Vec<_>>()
+        };
+
+        let survival = ScoringSystem::Survival {
+            time_multiplier: 0.5,
+        };
+        assert_eq!(points(survival), vec![(1, 5.0), (2, 2.5)]);
+        let destruction = ScoringSystem::Destruction {
+            crate_points: 2,
+            enemy_points: 10,
+        };
+        assert_eq!(points(destruction), vec![(1, 12.0), (2, 8.0)]);
+        let hybrid = ScoringSystem::Hybrid {
+            weights: HashMap::from([("wins".to_string(), 1.0), ("powerups".to_string(), 1.0)]),
+        };
+        assert_eq!(points(hybrid), vec![(2, 3.0), (1, 1.0)]);
+        let win_loss = ScoringSystem::WinLoss {
+            win_points: 3,
+            loss_points: 1,
+        };
+        let mut tracker = ScoreTracker::new(win_loss);
+        tracker.update_scores(&[GameResult::new(vec![1, 2], MatchOutcome::Draw(vec![1, 2]))]);
+        assert_eq!(tracker.get_rankings()[0].points, 2.0);
     }
 }
@@ -0,0 +1,192 @@
+use std::collections::{HashMap, HashSet};
+
+use events::events::bot_events::BotId;
+
+use super::scheduler::{GameId, GameMatch};
+use super::scoring::match_points;
+use super::GameResult;
+
+/// Most partial pairings tried before giving up on avoiding rematches.
+///
+/// Proving that no rematch-free pairing exists can take exponential time,
+/// so large fields fall back to pairing by rank instead.
+const PAIRING_BUDGET: usize = 10_000;
+
+/// Swiss-system pairing.
+///
+/// Each round pairs bots with equal or close scores, best first, without
+/// repeating an earlier pairing when that can be avoided. With an odd field
+/// the lowest-ranked bot that has not had a bye yet sits the round out and
+/// scores as if it had won.
+#[derive(Debug, Clone, Default)]
+pub struct SwissPairing {
+    points: HashMap<BotId, f32>,
+    played: HashSet<(BotId, BotId)>,
+    byes: HashSet<BotId>,
+}
+
+fn pair_key(a: BotId, b: BotId) -> (BotId, BotId) {
+    (a.min(b), a.max(b))
+}
+
+impl SwissPairing {
+    pub fn points(&self, bot: BotId) -> f32 {
+        self.points.get(&bot).copied().unwrap_or(0.0)
+    }
+
+    pub fn has_played(&self, a: BotId, b: BotId) -> bool {
+        self.played.contains(&pair_key(a, b))
+    }
+
+    /// Record a finished game.
+    pub fn record(&mut self, result: &GameResult) {
+        for (bot, points) in match_points(result) {
+            *self.points.entry(bot).or_default() += points;
+        }
+        for (i, &a) in result.participants.iter().enumerate() {
+            for &b in &result.participants[i + 1..] {
+                self.played.insert(pair_key(a, b));
+            }
+        }
+    }
+
+    /// Pair `bots`, given in registration order, for the next round and
+    /// number the games from `next_id`. Returns the games and the bot given
+    /// a bye, if any.
+    pub fn pair(
+        &mut self,
+        bots: &[BotId],
+        next_id: &mut GameId,
+    ) -> (Vec<GameMatch>, Option<BotId>) {
+        let mut standings = bots.to_vec();
+        // Stable, so equal scores keep registration order.
+        standings.sort_by(|a, b| self.points(*b).total_cmp(&self.points(*a)));
+
+        let mut bye = None;
+        if standings.len() % 2 == 1 {
+            let pos = standings
+                .iter()
+                .rposition(|bot| !self.byes.contains(bot))
+                .unwrap_or(standings.len() - 1);
+            let bot = standings.remove(pos);
+            self.byes.insert(bot);
+            *self.points.entry(bot).or_default() += 1.0;
+            bye = Some(bot);
+        }
+
+        let mut budget = PAIRING_BUDGET;
+        let pairs = self
+            .pair_without_rematches(&standings, &mut budget)
+            .unwrap_or_else(|| standings.chunks(2).map(|pair| (pair[0], pair[1])).collect());
+        let games = pairs
+            .into_iter()
+            .map(|(a, b)| {
+                let id = *next_id;
+                *next_id += 1;
+                GameMatch {
+                    id,
+                    participants: vec![a, b],
+                }
+            })
+            .collect();
+        (games, bye)
+    }
+
+    /// Pair the bots of `standings` without rematches, each with the
+    /// closest-ranked opponent still available; `None` if that is impossible
+    /// or takes more than `budget` attempts.
+    fn pair_without_rematches(
+        &self,
+        standings: &[BotId],
+        budget: &mut usize,
+    ) -> Option<Vec<(BotId, BotId)>> {
+        let Some((&first, rest)) = standings.split_first() else {
+            return Some(Vec::new());
+        };
+        for (i, &opponent) in rest.iter().enumerate() {
+            if self.has_played(first, opponent) {
+                continue;
+            }
+            if *budget == 0 {
+                return None;
+            }
+            *budget -= 1;
+            let mut remaining = rest.to_vec();
+            remaining.remove(i);
+            if let Some(mut pairs) = self.pair_without_rematches(&remaining, budget) {
+                pairs.insert(0, (first, opponent));
+                return Some(pairs);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MatchOutcome;
+
+    fn play(swiss: &mut SwissPairing, games: &[GameMatch], winners: &[BotId]) {
+        for (game, &winner) in games.iter().zip(winners) {
+            let result = GameResult::new(game.participants.clone(), MatchOutcome::Winner(winner));
+            swiss.record(&result);
+        }
+    }
+
+    #[test]
+    fn pairs_by_score_without_rematches() {
+        let mut swiss = SwissPairing::default();
+        let mut next_id = 0;
+        let bots = [0, 1, 2, 3];
+        let (first, bye) = swiss.pair(&bots, &mut next_id);
+        assert_eq!(bye, None);
+        assert_eq!(first[0].participants, vec![0, 1]);
+        assert_eq!(first[1].participants, vec![2, 3]);
+        play(&mut swiss, &first, &[0, 2]);
+
+        let (second, _) = swiss.pair(&bots, &mut next_id);
+        assert_eq!(second[0].participants, vec![0, 2]);
+        assert_eq!(second[1].participants, vec![1, 3]);
+        assert_eq!(second[0].id, 2);
+        play(&mut swiss, &second, &[0, 3]);
+
+        // The leader already met 2, so it plays 3 instead.
+        let (third, _) = swiss.pair(&bots, &mut next_id);
+        assert_eq!(third[0].participants, vec![0, 3]);
+        assert_eq!(third[1].participants, vec![2, 1]);
+    }
+
+    #[test]
+    fn odd_fields_rotate_the_bye() {
+        let mut swiss = SwissPairing::default();
+        let mut next_id = 0;
+        let bots = [0, 1, 2];
+        let (games, bye) = swiss.pair(&bots, &mut next_id);
+        assert_eq!(bye, Some(2));
+        assert_eq!(swiss.points(2), 1.0);
+        play(&mut swiss, &games, &[0]);
+
+        let (_, bye) = swiss.pair(&bots, &mut next_id);
+        assert_eq!(bye, Some(1));
+    }
+
+    #[test]
+    fn large_fields_without_a_fresh_pairing_fall_back_quickly() {
+        let mut swiss = SwissPairing::default();
+        let mut next_id = 0;
+        let bots: Vec<BotId> = (0..40).collect();
+        // The last bot has met everyone, so every rematch-free pairing of
+        // the others is a dead end.
+        for &bot in &bots[..39] {
+            swiss.played.insert(pair_key(bot, 39));
+        }
+
+        let (games, bye) = swiss.pair(&bots, &mut next_id);
+        assert_eq!(bye, None);
+        assert_eq!(games.len(), 20);
+        let mut seen: Vec<BotId> = games.iter().flat_map(|g| g.participants.clone()).collect();
+        seen.sort_unstable();
+        assert_eq!(seen, bots);
+    }
+}
@@ -0,0 +1,175 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::Duration;
+
+use common::Direction;
+use engine::bot::remote::{ClientMessage, PROTOCOL_VERSION, ServerMessage};
+use engine::{BotError, Engine, EngineConfig, RemoteBotListener};
+use events::bus::EventBus;
+use events::events::BotDecision;
+use state::GameGrid;
+
+/// Stand-in for a bot written as a separate program: it speaks the protocol
+/// over a plain socket and walks right on every tick.
+struct Client {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Client {
+    fn connect(addr: SocketAddr, protocol: u32) -> Self {
+        let writer = TcpStream::connect(addr).unwrap();
+        let mut client = Self {
+            reader: BufReader::new(writer.try_clone().unwrap()),
+            writer,
+        };
+        client.send(&ClientMessage::Hello {
+            name: "walker".into(),
+            version: "0.1.0".into(),
+            protocol,
+        });
+        client
+    }
+
+    fn send(&mut self, message: &ClientMessage) {
+        let mut line = serde_json::to_string(message).unwrap();
+        line.push('\n');
+        self.writer.write_all(line.as_bytes()).unwrap();
+    }
+
+    fn receive(&mut self) -> Option<ServerMessage> {
+        let mut line = String::new();
+        match self.reader.read_line(&mut line).unwrap() {
+            0 => None,
+            _ => Some(serde_json::from_str(&line).unwrap()),
+        }
+    }
+
+    /// Answer observations until the match stops, returning the messages seen.
+    fn play(mut self) -> Vec<ServerMessage> {
+        let mut seen = Vec::new();
+        while let Some(message) = self.receive() {
+            if let ServerMessage::Observation { tick, .. } = message {
+                self.send(&ClientMessage::Decision {
+                    tick,
+                    decision: BotDecision::Move(Direction::Right),
+                });
+            }
+            let stopped = message == ServerMessage::Stopped;
+            seen.push(message);
+            if stopped {
+                break;
+            }
+        }
+        seen
+    }
+}
+
+fn corridor_engine() -> Engine {
+    let grid = GameGrid::from_ascii("######\n#1...#\n######").unwrap();
+    let cfg = EngineConfig {
+        width: 6,
+        height: 3,
+        ..EngineConfig::default()
+    };
+    let (engine, _rx) =
+        Engine::with_components(cfg, Arc::new(RwLock::new(grid)), Arc::new(EventBus::new()));
+    engine
+}
+
+#[tokio::test]
+async fn remote_bots_play_like_local_ones() {
+    let listener = RemoteBotListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let client = thread::spawn(move || Client::connect(addr, PROTOCOL_VERSION).play());
+
+    let connection = listener.accept().unwrap();
+    assert_eq!(connection.name(), "walker");
+    assert_eq!(connection.version(), "0.1.0");
+    let mut engine = corridor_engine();
+    let id = engine
+        .spawn_remote_bot(connection, Duration::from_secs(2))
+        .unwrap();
+    for _ in 0..2 {
+        engine.tick().await.unwrap();
+    }
+    let position = engine.grid().read().unwrap().agents()[0].position;
+    assert_eq!(position, (3, 1));
+    engine.shutdown();
+
+    let seen = client.join().unwrap();
+    match &seen[0] {
+        ServerMessage::Welcome {
+            bot_id,
+            protocol,
+            grid,
+            ..
+        } => {
+            assert_eq!(*bot_id, id);
+            assert_eq!(*protocol, PROTOCOL_VERSION);
+            assert_eq!((grid.width, grid.height), (6, 3));
+        }
+        other => panic!("expected a welcome, got {:?}", other),
+    }
+    let ticks: Vec<u64> = seen
+        .iter()
+        .filter_map(|m| match m {
+            ServerMessage::Observation { tick, .. } => Some(*tick),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(ticks.len(), 2);
+    assert_eq!(seen.last(), Some(&ServerMessage::Stopped));
+}
+
+#[test]
+fn other_protocol_versions_are_rejected() {
+    let listener = RemoteBotListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let client = thread::spawn(move || {
+        let mut client = Client::connect(addr, PROTOCOL_VERSION + 1);
+        client.receive()
+    });
+
+    assert!(matches!(listener.accept(), Err(BotError::Remote(_))));
+    assert!(matches!(
+        client.join().unwrap(),
+        Some(ServerMessage::Rejected { .. })
+    ));
+}
+
+#[test]
+fn silent_clients_do_not_hold_up_others() {
+    let listener = RemoteBotListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let _silent = TcpStream::connect(addr).unwrap();
+    let client = thread::spawn(move || Client::connect(addr, PROTOCOL_VERSION));
+
+    let start = std::time::Instant::now();
+    let connection = listener.accept().unwrap();
+    assert_eq!(connection.name(), "walker");
+    assert!(start.elapsed() < engine::bot::remote::HANDSHAKE_TIMEOUT);
+    drop(client.join().unwrap());
+}
+
+#[test]
+fn endless_lines_are_cut_off() {
+    let listener = RemoteBotListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let client = thread::spawn(move || {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        let chunk = vec![b' '; 4096];
+        // The engine drops the connection long before this ends.
+        for _ in 0..64 * 1024 {
+            if stream.write_all(&chunk).is_err() {
+                return true;
+            }
+        }
+        false
+    });
+
+    assert!(matches!(listener.accept(), Err(BotError::Remote(_))));
+    assert!(client.join().unwrap());
+}
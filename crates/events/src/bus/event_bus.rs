@@ -173,6 +173,7 @@ mod tests {
         bus.emit(
             Event::Bot(BotEvent::Decision {
                 bot_id: 1,
+                tick: 1,
                 decision: BotDecision::Wait,
             }),
             EventPriority::Normal,
@@ -1,5 +1,6 @@
 //! Bomb-related events.
 
+use common::Direction;
 use serde::{Deserialize, Serialize};
 
 /// Identifier for an agent.
@@ -8,20 +9,15 @@ pub type AgentId = usize;
 /// Grid position for events.
 pub type Position = (u16, u16);
 
-/// Possible power-up types affecting bombs.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub enum PowerUpType {
-    /// Increases the number of bombs an agent can carry.
-    BombCount,
-    /// Extends the blast radius of bombs.
-    BlastRadius,
-}
+pub use state::{BombId, PowerUpType};
 
 /// Events related to bomb mechanics.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum BombEvent {
     /// A bomb was placed by an agent.
     Placed {
+        /// Identifier of the bomb.
+        bomb_id: BombId,
         /// Identifier of the agent placing the bomb.
         agent_id: AgentId,
         /// Grid position where the bomb was placed.
@@ -29,17 +25,46 @@ pub enum BombEvent {
     },
     /// A bomb exploded at a position with a given radius.
     Exploded {
+        /// Identifier of the bomb.
+        bomb_id: BombId,
         /// Center position of the explosion.
         position: Position,
         /// Blast radius of the explosion.
         radius: u32,
     },
+    /// Soft crates were destroyed by a blast.
+    CratesDestroyed {
+        /// Bomb credited with the blast, the one that set off any others.
+        bomb_id: BombId,
+        /// Owner of that bomb.
+        agent_id: AgentId,
+        /// Tiles of the destroyed crates.
+        positions: Vec<Position>,
+    },
     /// A chain reaction occurred affecting multiple positions.
     ChainReaction {
+        /// Bombs that went off, starting with the one that set off the others.
+        bombs: Vec<BombId>,
         /// Positions impacted during the chain reaction.
         positions: Vec<Position>,
     },
-    /// An agent collected a power-up affecting bombs.
+    /// An agent kicked a bomb, which slides until something stops it.
+    Kicked {
+        /// Identifier of the bomb.
+        bomb_id: BombId,
+        /// Identifier of the kicking agent.
+        agent_id: AgentId,
+        /// Direction the bomb slides in.
+        direction: Direction,
+    },
+    /// An agent set off one of its remote bombs.
+    Detonated {
+        /// Identifier of the bomb.
+        bomb_id: BombId,
+        /// Identifier of the agent detonating the bomb.
+        agent_id: AgentId,
+    },
+    /// An agent collected a power-up.
     PowerUpCollected {
         /// Identifier of the agent collecting the power-up.
         agent_id: AgentId,
@@ -55,6 +80,7 @@ mod tests {
     #[test]
     fn bomb_event_serializes() {
         let ev = BombEvent::Placed {
+            bomb_id: BombId(0),
             agent_id: 1,
             position: (0, 0),
         };
@@ -4,6 +4,7 @@
 pub type BotId = usize;
 
 use common::Direction;
+use state::BombId;
 use serde::{Deserialize, Serialize};
 
 /// Decisions that a bot might produce.
@@ -15,6 +16,9 @@ pub enum BotDecision {
     Move(Direction),
     /// Bot decided to place a bomb.
     PlaceBomb,
+    /// Bot decided to set off one of its remote bombs; without an id, the
+    /// oldest one goes off.
+    Detonate(Option<BombId>),
 }
 
 /// Events emitted by or for bots.
@@ -24,6 +28,8 @@ pub enum BotEvent {
     Decision {
         /// Identifier of the bot.
         bot_id: BotId,
+        /// Tick the decision answers, taken from the engine's tick request.
+        tick: u64,
         /// Decision made.
         decision: BotDecision,
     },
@@ -4,11 +4,23 @@
 pub type EntityId = usize;
 /// Grid position represented as `(x, y)` coordinates.
 pub type Position = (u16, u16);
-/// Identifier for bombs.
-pub type BombId = usize;
+pub use state::BombId;
 
 use serde::{Deserialize, Serialize};
 
+/// How an agent's intended move was settled during simultaneous resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MoveOutcome {
+    /// The agent reached the tile it wanted to enter.
+    Moved,
+    /// The target tile is outside the grid, not walkable, or held by an agent that did not move.
+    Blocked,
+    /// The agent lost a conflict with another moving agent and stayed on its tile.
+    Yielded,
+    /// The agent collided with another moving agent and was pushed back one tile.
+    Bounced,
+}
+
 /// Events emitted by the game engine.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum GameEvent {
@@ -32,6 +44,61 @@ pub enum GameEvent {
         /// Bomb power.
         power: u8,
     },
+    /// An agent's intended move was resolved against all other moves of the tick.
+    MoveResolved {
+        /// Entity identifier.
+        entity_id: EntityId,
+        /// Position at the start of the tick.
+        from: Position,
+        /// Tile the agent tried to enter, if it lies on the grid.
+        intended: Option<Position>,
+        /// Position after resolution.
+        to: Position,
+        /// How the move was settled.
+        outcome: MoveOutcome,
+    },
+    /// An explosion hit an agent and cost it a life.
+    AgentHit {
+        /// Entity identifier.
+        entity_id: EntityId,
+        /// Tile the agent was standing on.
+        position: Position,
+        /// Lives the agent has left.
+        lives_left: u8,
+    },
+    /// A hit agent returned to the grid on a spawn point.
+    AgentRespawned {
+        /// Entity identifier.
+        entity_id: EntityId,
+        /// Spawn point the agent was placed on.
+        position: Position,
+    },
+    /// An agent lost its last life and left the match.
+    AgentEliminated {
+        /// Entity identifier.
+        entity_id: EntityId,
+        /// Tick on which the agent was eliminated.
+        tick: u64,
+    },
+    /// A tile will collapse into a wall during sudden death.
+    TileCollapsing {
+        /// Tile that will collapse.
+        position: Position,
+        /// Tick on which it collapses.
+        tick: u64,
+    },
+    /// A tile collapsed into a wall, crushing whatever stood on it.
+    TileCollapsed {
+        /// Tile that collapsed.
+        position: Position,
+        /// Tick on which it collapsed.
+        tick: u64,
+    },
+    /// The engine requests a decision from every bot for the given tick.
+    TickRequested {
+        /// Tick number the decisions will be applied to.
+        tick: u64,
+    },
     /// A game tick finished executing.
     TickCompleted {
         /// Tick number.
@@ -9,7 +9,7 @@ use state::grid::GridDelta;
 
 pub use bomb_events::{BombEvent, PowerUpType};
 pub use bot_events::{BotDecision, BotEvent};
-pub use game_events::GameEvent;
+pub use game_events::{GameEvent, MoveOutcome};
 pub use system_events::SystemEvent;
 
 /// Wrapper enum combining all event categories.
@@ -12,6 +12,8 @@ pub mod serialization;
 
 pub use bus::{EventBus, EventFilter, SubscriberId};
 pub use error::EventBusError;
-pub use events::{BombEvent, BotDecision, BotEvent, Event, GameEvent, PowerUpType, SystemEvent};
+pub use events::{
+    BombEvent, BotDecision, BotEvent, Event, GameEvent, MoveOutcome, PowerUpType, SystemEvent,
+};
 pub use queue::EventPriority;
 pub use serialization::{Transition, TransitionRecorder, decoder, encoder};
@@ -1,6 +1,9 @@
 //! Attack enemy goal implementation.
 
+use std::collections::HashSet;
+
 use super::{Action, BotId, Goal, GoalError, GoalType};
+use bombs::affected_tiles;
 use state::{GameState, Tile};
 
 /// Goal to attack nearby enemies strategically.
@@ -13,6 +16,9 @@ impl Goal for AttackEnemyGoal {
     }
 
     fn get_priority(&self, state: &GameState, bot_id: BotId) -> f32 {
+        if self.trap_sprung(state, bot_id) {
+            return 100.0; // An enemy walked into one of our remote bombs
+        }
         if let Some(enemy_pos) = self.find_nearest_enemy(state, bot_id) {
             if let Some(bot_pos) = self.get_bot_position(state, bot_id) {
                 let distance = self.manhattan_distance(bot_pos, enemy_pos);
@@ -53,6 +59,9 @@ impl Goal for AttackEnemyGoal {
     }
 
     fn is_completed(&self, state: &GameState, bot_id: BotId) -> bool {
+        if self.trap_sprung(state, bot_id) {
+            return false;
+        }
         // Goal completed when no enemies nearby or we're in attack position
         if let Some(enemy_pos) = self.find_nearest_enemy(state, bot_id) {
             if let Some(bot_pos) = self.get_bot_position(state, bot_id) {
@@ -67,11 +76,15 @@ impl Goal for AttackEnemyGoal {
     }
 
     fn plan(&self, state: &GameState, bot_id: BotId) -> Result<Vec<Action>, GoalError> {
+        if self.trap_sprung(state, bot_id) {
+            return Ok(vec![Action::Detonate]);
+        }
         if let Some(enemy_pos) = self.find_nearest_enemy(state, bot_id) {
             if let Some(bot_pos) = self.get_bot_position(state, bot_id) {
                 let distance = self.manhattan_distance(bot_pos, enemy_pos);
                 if distance <= 2 {
-                    // Close enough, place bomb and prepare to escape
+                    // Close enough, place bomb and prepare to escape. Remote
+                    // bombs stay behind as a trap until an enemy walks in.
                     Ok(vec![Action::PlaceBomb, Action::EscapeDanger])
                 } else {
                     // Move closer to enemy
@@ -87,6 +100,47 @@ impl Goal for AttackEnemyGoal {
 }
 
 impl AttackEnemyGoal {
+    /// Whether one of the bot's remote bombs would catch an enemy, but not
+    /// the bot itself, if it went off now.
+    ///
+    /// Blasts follow the engine's explosion model: walls stop them and crates
+    /// absorb them unless the bomb pierces.
+    fn trap_sprung(&self, state: &GameState, bot_id: BotId) -> bool {
+        let Some(bot_pos) = self.get_bot_position(state, bot_id) else {
+            return false;
+        };
+        let snapshot = state.grid.snapshot();
+        let width = state.grid.width();
+        let size = (width as u16, state.grid.height() as u16);
+        let mut walls = HashSet::new();
+        let mut crates = HashSet::new();
+        for (i, tile) in snapshot.tiles().iter().enumerate() {
+            let pos = ((i % width) as u16, (i / width) as u16);
+            match tile {
+                Tile::Wall => {
+                    walls.insert(pos);
+                }
+                Tile::SoftCrate => {
+                    crates.insert(pos);
+                }
+                _ => {}
+            }
+        }
+        snapshot
+            .bombs()
+            .iter()
+            .filter(|b| b.owner == bot_id && b.remote)
+            .any(|b| {
+                let blast =
+                    affected_tiles(b.position, b.power, size, &walls, &crates, b.pierce);
+                !blast.contains(&bot_pos)
+                    && snapshot
+                        .agents()
+                        .iter()
+                        .any(|a| a.id != bot_id && blast.contains(&a.position))
+            })
+    }
+
     fn find_nearest_enemy(&self, state: &GameState, bot_id: BotId) -> Option<(u16, u16)> {
         let bot_pos = self.get_bot_position(state, bot_id)?;
         let snapshot = state.grid.snapshot();
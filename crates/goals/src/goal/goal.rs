@@ -22,6 +22,8 @@ pub enum Action {
     },
     /// Escape from danger area.
     EscapeDanger,
+    /// Set off the oldest of the bot's remote bombs.
+    Detonate,
 }
 
 /// High-level goal categories.
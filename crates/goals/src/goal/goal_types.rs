@@ -67,7 +67,7 @@ impl CollectPowerUpGoal {
         for y in 0..state.grid.height() {
             for x in 0..state.grid.width() {
                 let index = y * state.grid.width() + x;
-                if index < tiles.len() && tiles[index] == Tile::PowerUp {
+                if index < tiles.len() && matches!(tiles[index], Tile::PowerUp(_)) {
                     let powerup_pos = (x as u16, y as u16);
                     let distance = self.manhattan_distance(bot_pos, powerup_pos);
                     if distance < min_distance {
@@ -259,7 +259,7 @@ impl AvoidDangerGoal {
         }
         
         match tiles[index] {
-            Tile::Empty | Tile::PowerUp => true,
+            Tile::Empty | Tile::PowerUp(_) => true,
             Tile::Wall | Tile::SoftCrate | Tile::Explosion => false,
         }
     }
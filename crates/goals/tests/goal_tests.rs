@@ -1,5 +1,6 @@
-use goals::goal::{Action, AvoidDangerGoal, CollectPowerUpGoal, Goal, GoalType};
-use state::GameState;
+use goals::goal::{Action, AttackEnemyGoal, AvoidDangerGoal, CollectPowerUpGoal, Goal, GoalType};
+use state::grid::GridDelta;
+use state::{AgentState, Bomb, BombId, GameState, Tile};
 
 #[test]
 fn goal_types_and_priorities() {
@@ -16,3 +17,77 @@ fn goal_types_and_priorities() {
     let plan = collect.plan(&state, bot_id).unwrap();
     assert_eq!(plan, vec![Action::Wait]);
 }
+
+#[test]
+fn attack_goal_springs_remote_traps() {
+    let mut state = GameState::new(5, 5);
+    state.apply_delta(GridDelta::AddAgent(AgentState::new(0, (0, 1))));
+    state.apply_delta(GridDelta::AddAgent(AgentState::new(1, (2, 2))));
+    let trap = Bomb::new(BombId(0), 0, (2, 0), 9, 2);
+    state.apply_delta(GridDelta::AddBomb(trap.clone()));
+    let goal = AttackEnemyGoal;
+    assert_ne!(goal.plan(&state, 0).unwrap(), vec![Action::Detonate]);
+
+    state.apply_delta(GridDelta::UpdateBomb(Bomb {
+        remote: true,
+        ..trap
+    }));
+    assert_eq!(goal.plan(&state, 0).unwrap(), vec![Action::Detonate]);
+    assert!(!goal.is_completed(&state, 0));
+}
+
+#[test]
+fn attack_goal_respects_walls_crates_and_pierce() {
+    let mut state = GameState::new(7, 3);
+    state.apply_delta(GridDelta::AddAgent(AgentState::new(0, (0, 0))));
+    state.apply_delta(GridDelta::AddAgent(AgentState::new(1, (4, 1))));
+    let trap = Bomb {
+        remote: true,
+        ..Bomb::new(BombId(0), 0, (2, 1), 9, 3)
+    };
+    state.apply_delta(GridDelta::AddBomb(trap.clone()));
+    let goal = AttackEnemyGoal;
+    assert_eq!(goal.plan(&state, 0).unwrap(), vec![Action::Detonate]);
+
+    // A wall shields the enemy.
+    let wall = GridDelta::SetTile {
+        x: 3,
+        y: 1,
+        tile: Tile::Wall,
+    };
+    state.apply_delta(wall);
+    assert_ne!(goal.plan(&state, 0).unwrap(), vec![Action::Detonate]);
+
+    // A crate shields it too, unless the bomb pierces.
+    state.apply_delta(GridDelta::SetTile {
+        x: 3,
+        y: 1,
+        tile: Tile::SoftCrate,
+    });
+    assert_ne!(goal.plan(&state, 0).unwrap(), vec![Action::Detonate]);
+    state.apply_delta(GridDelta::UpdateBomb(Bomb {
+        pierce: true,
+        ..trap
+    }));
+    assert_eq!(goal.plan(&state, 0).unwrap(), vec![Action::Detonate]);
+}
+
+#[test]
+fn attack_goal_knows_walls_shield_the_bot() {
+    let mut state = GameState::new(5, 5);
+    state.apply_delta(GridDelta::AddAgent(AgentState::new(0, (2, 0))));
+    state.apply_delta(GridDelta::AddAgent(AgentState::new(1, (4, 2))));
+    state.apply_delta(GridDelta::AddBomb(Bomb {
+        remote: true,
+        ..Bomb::new(BombId(0), 0, (2, 2), 9, 2)
+    }));
+    let goal = AttackEnemyGoal;
+    assert_ne!(goal.plan(&state, 0).unwrap(), vec![Action::Detonate]);
+
+    state.apply_delta(GridDelta::SetTile {
+        x: 2,
+        y: 1,
+        tile: Tile::Wall,
+    });
+    assert_eq!(goal.plan(&state, 0).unwrap(), vec![Action::Detonate]);
+}
@@ -13,6 +13,18 @@ pub struct AgentState {
     pub bombs_left: u8,
     /// Blast radius of bombs placed by this agent.
     pub power: u8,
+    /// Tiles the agent may move per tick.
+    pub speed: u8,
+    /// Whether the agent can kick bombs.
+    pub kick: bool,
+    /// Whether the agent's bombs wait for remote detonation.
+    pub remote: bool,
+    /// Whether the agent's blasts pass through soft crates.
+    pub pierce: bool,
+    /// Lives left, including the current one.
+    pub lives: u8,
+    /// First tick on which explosions can hit the agent again.
+    pub invulnerable_until: u64,
 }
 
 impl AgentState {
@@ -23,6 +35,17 @@ impl AgentState {
             position,
             bombs_left: 1,
             power: 1,
+            speed: 1,
+            kick: false,
+            remote: false,
+            pierce: false,
+            lives: 1,
+            invulnerable_until: 0,
         }
     }
+
+    /// Whether an explosion on the agent's tile during `tick` hits it.
+    pub fn is_vulnerable(&self, tick: u64) -> bool {
+        tick >= self.invulnerable_until
+    }
 }
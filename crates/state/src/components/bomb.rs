@@ -1,10 +1,17 @@
 //! Bomb component with timing and properties.
 
+use common::Direction;
 use serde::{Deserialize, Serialize};
 
+/// Unique identifier of a bomb, allocated when it is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct BombId(pub u32);
+
 /// Live bomb placed on the grid.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Bomb {
+    /// Identifier of this bomb.
+    pub id: BombId,
     /// Identifier of the owner agent.
     pub owner: usize,
     /// Bomb position on the grid.
@@ -17,18 +24,23 @@ pub struct Bomb {
     pub pierce: bool,
     /// Whether the bomb can be detonated remotely.
     pub remote: bool,
+    /// Direction the bomb is sliding in after being kicked.
+    #[serde(default)]
+    pub sliding: Option<Direction>,
 }
 
 impl Bomb {
     /// Creates a new bomb instance.
-    pub fn new(owner: usize, position: (u16, u16), timer: u8, power: u8) -> Self {
+    pub fn new(id: BombId, owner: usize, position: (u16, u16), timer: u8, power: u8) -> Self {
         Self {
+            id,
             owner,
             position,
             timer,
             power,
             pierce: false,
             remote: false,
+            sliding: None,
         }
     }
 
@@ -4,6 +4,9 @@
 pub mod agent;
 /// Bomb-related data structures.
 pub mod bomb;
+/// Power-up kinds.
+pub mod powerup;
 
 pub use agent::AgentState;
-pub use bomb::Bomb;
+pub use bomb::{Bomb, BombId};
+pub use powerup::PowerUpType;
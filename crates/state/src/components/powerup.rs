@@ -0,0 +1,49 @@
+//! Power-up kinds dropped by destroyed crates.
+
+use serde::{Deserialize, Serialize};
+
+/// Kinds of power-up an agent can collect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PowerUpType {
+    /// Increases the number of bombs an agent can carry.
+    BombCount,
+    /// Extends the blast radius of bombs.
+    BlastRadius,
+    /// Lets the agent move an extra tile per tick.
+    Speed,
+    /// Lets the agent kick bombs.
+    Kick,
+    /// Lets the agent detonate its bombs remotely.
+    Remote,
+    /// Makes the agent's blasts pass through soft crates.
+    Pierce,
+}
+
+impl PowerUpType {
+    /// Every power-up kind in a fixed order.
+    pub const ALL: [PowerUpType; 6] = [
+        PowerUpType::BombCount,
+        PowerUpType::BlastRadius,
+        PowerUpType::Speed,
+        PowerUpType::Kick,
+        PowerUpType::Remote,
+        PowerUpType::Pierce,
+    ];
+
+    /// Symbol used for this power-up in plain-text maps.
+    pub fn symbol(self) -> char {
+        match self {
+            PowerUpType::BombCount => 'b',
+            PowerUpType::BlastRadius => 'f',
+            PowerUpType::Speed => 's',
+            PowerUpType::Kick => 'k',
+            PowerUpType::Remote => 'r',
+            PowerUpType::Pierce => 'p',
+        }
+    }
+
+    /// Power-up written as `symbol` in plain-text maps.
+    pub fn from_symbol(symbol: char) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.symbol() == symbol)
+    }
+}
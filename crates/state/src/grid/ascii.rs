@@ -0,0 +1,191 @@
+//! Plain-text map layouts for hand-authored arenas.
+//!
+//! Each line is one row of the map:
+//!
+//! | Symbol      | Meaning                          |
+//! |-------------|----------------------------------|
+//! | `#`         | indestructible wall              |
+//! | `+`         | soft crate                       |
+//! | `.`         | empty tile                       |
+//! | `b`         | bomb count power-up              |
+//! | `f`         | blast radius power-up            |
+//! | `s`         | speed power-up                   |
+//! | `k`         | kick power-up                    |
+//! | `r`         | remote detonation power-up       |
+//! | `p`         | pierce power-up                  |
+//! | `1` - `8`   | empty tile holding that spawn    |
+//!
+//! Blank lines and trailing whitespace are ignored. Spawns must be numbered
+//! from `1` without gaps and every spawn must reach every other one.
+
+use super::generator::{MapError, MapLayout};
+use super::tile::Tile;
+use crate::components::PowerUpType;
+
+/// Highest spawn number a layout may use.
+pub const MAX_SPAWNS: u8 = 8;
+
+impl MapLayout {
+    /// Parse a layout from its plain-text form.
+    pub fn from_ascii(text: &str) -> Result<Self, MapError> {
+        let rows: Vec<&str> = text
+            .lines()
+            .map(str::trim_end)
+            .filter(|line| !line.is_empty())
+            .collect();
+        let Some(first) = rows.first() else {
+            return Err(MapError::EmptyLayout);
+        };
+        let width = first.chars().count();
+        let height = rows.len();
+
+        let mut tiles = Vec::with_capacity(width * height);
+        let mut numbered: [Option<(u16, u16)>; MAX_SPAWNS as usize] = [None; MAX_SPAWNS as usize];
+        for (y, row) in rows.iter().enumerate() {
+            let found = row.chars().count();
+            if found != width {
+                return Err(MapError::RaggedRow {
+                    row: y + 1,
+                    expected: width,
+                    found,
+                });
+            }
+            for (x, symbol) in row.chars().enumerate() {
+                let tile = match symbol {
+                    '#' => Tile::Wall,
+                    '+' => Tile::SoftCrate,
+                    '.' => Tile::Empty,
+                    '1'..='8' => {
+                        let number = symbol as u8 - b'0';
+                        let slot = &mut numbered[number as usize - 1];
+                        if slot.is_some() {
+                            return Err(MapError::DuplicateSpawn(number));
+                        }
+                        *slot = Some((x as u16, y as u16));
+                        Tile::Empty
+                    }
+                    _ => match PowerUpType::from_symbol(symbol) {
+                        Some(kind) => Tile::PowerUp(kind),
+                        None => {
+                            return Err(MapError::UnknownSymbol {
+                                symbol,
+                                row: y + 1,
+                                column: x + 1,
+                            });
+                        }
+                    },
+                };
+                tiles.push(tile);
+            }
+        }
+
+        let count = numbered.iter().filter(|s| s.is_some()).count();
+        if let Some(gap) = numbered[..count].iter().position(Option::is_none) {
+            return Err(MapError::MissingSpawn(gap as u8 + 1));
+        }
+        let spawns: Vec<(u16, u16)> = numbered.iter().flatten().copied().collect();
+
+        let layout = MapLayout {
+            width,
+            height,
+            tiles,
+            spawns,
+        };
+        if !layout.spawns_connected() {
+            return Err(MapError::Disconnected);
+        }
+        Ok(layout)
+    }
+
+    /// Render the layout in the form read by [`MapLayout::from_ascii`].
+    ///
+    /// Explosions are written as empty tiles.
+    pub fn to_ascii(&self) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let spawn = self
+                    .spawns
+                    .iter()
+                    .position(|&(sx, sy)| (sx as usize, sy as usize) == (x, y));
+                let symbol = match (spawn, self.tiles[y * self.width + x]) {
+                    (Some(i), _) => char::from(b'1' + i as u8),
+                    (None, Tile::Wall) => '#',
+                    (None, Tile::SoftCrate) => '+',
+                    (None, Tile::PowerUp(kind)) => kind.symbol(),
+                    (None, Tile::Empty | Tile::Explosion) => '.',
+                };
+                out.push(symbol);
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARENA: &str = "
+#######
+#1.+.2#
+#.#k#.#
+#+...+#
+#.#.#.#
+#3.+.4#
+#######
+";
+
+    #[test]
+    fn parses_tiles_and_spawns() {
+        let layout = MapLayout::from_ascii(ARENA).unwrap();
+        assert_eq!((layout.width, layout.height), (7, 7));
+        assert_eq!(layout.spawns, vec![(1, 1), (5, 1), (1, 5), (5, 5)]);
+        assert_eq!(layout.tile(3, 1), Some(Tile::SoftCrate));
+        assert_eq!(layout.tile(3, 2), Some(Tile::PowerUp(PowerUpType::Kick)));
+        assert_eq!(layout.tile(1, 1), Some(Tile::Empty));
+        assert_eq!(layout.to_ascii().trim(), ARENA.trim());
+    }
+
+    #[test]
+    fn reports_malformed_layouts() {
+        assert_eq!(MapLayout::from_ascii("\n  \n"), Err(MapError::EmptyLayout));
+        assert_eq!(
+            MapLayout::from_ascii("###\n#1\n###"),
+            Err(MapError::RaggedRow {
+                row: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            MapLayout::from_ascii("###\n#x#\n###"),
+            Err(MapError::UnknownSymbol {
+                symbol: 'x',
+                row: 2,
+                column: 2
+            })
+        );
+        assert_eq!(
+            MapLayout::from_ascii("#####\n#1.1#\n#####"),
+            Err(MapError::DuplicateSpawn(1))
+        );
+        assert_eq!(
+            MapLayout::from_ascii("#####\n#1.3#\n#####"),
+            Err(MapError::MissingSpawn(2))
+        );
+    }
+
+    #[test]
+    fn rejects_unreachable_spawns() {
+        let walled = "
+#####
+#1#2#
+#####
+";
+        assert_eq!(MapLayout::from_ascii(walled), Err(MapError::Disconnected));
+        // Crates can be bombed away, so they do not disconnect spawns.
+        assert!(MapLayout::from_ascii("#####\n#1+2#\n#####").is_ok());
+    }
+}
@@ -1,5 +1,5 @@
 use super::tile::Tile;
-use crate::components::{AgentState, Bomb};
+use crate::components::{AgentState, Bomb, BombId};
 use serde::{Deserialize, Serialize};
 
 /// Changes applied to the grid, broadcast to subscribers.
@@ -19,10 +19,16 @@ pub enum GridDelta {
     },
     /// Add a bomb to the grid.
     AddBomb(Bomb),
+    /// Remove the bomb with the given id from the grid.
+    RemoveBomb(BombId),
+    /// Replace the state of the bomb with the same id.
+    UpdateBomb(Bomb),
     /// Add an agent to the grid.
     AddAgent(AgentState),
     /// Move an agent to a new position.
     MoveAgent(usize, (u16, u16)),
     /// Remove an agent from the grid.
     RemoveAgent(usize),
+    /// Replace the state of the agent with the same id.
+    UpdateAgent(AgentState),
 }
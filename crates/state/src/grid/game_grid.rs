@@ -2,8 +2,9 @@
 #![allow(unsafe_code)]
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use super::generator::{ClassicGenerator, MapError, MapGenerator, MapLayout};
 use super::{delta::GridDelta, tile::Tile};
-use crate::components::{AgentState, Bomb};
+use crate::components::{AgentState, Bomb, BombId};
 use crate::state::snapshot::{SnapshotInner, SnapshotView};
 use crossbeam_epoch::{self as epoch, Atomic, Owned};
 use tokio::sync::watch;
@@ -17,6 +18,8 @@ pub struct GameGrid {
     tiles: Vec<Tile>,
     bombs: Vec<Bomb>,
     agents: Vec<AgentState>,
+    spawns: Vec<(u16, u16)>,
+    next_bomb_id: u32,
     version: AtomicU64,
     snapshot: Atomic<SnapshotInner>,
     delta_tx: watch::Sender<GridDelta>,
@@ -30,123 +33,40 @@ pub struct ObservationDelta {
 }
 
 impl GameGrid {
-    /// Creates a new grid following the classic Bomberman pattern:
-    /// - Solid grey walls in a checkerboard pattern
-    /// - Brown breakable blocks filling remaining spaces
-    /// - Clear spawn zones for botssdd
+    /// Creates a new grid with the layout of [`ClassicGenerator`].
     pub fn new(width: usize, height: usize) -> Self {
-        let mut tiles = vec![Tile::Empty; width * height];
-        
-        // Add solid walls around the border
-        for x in 0..width {
-            tiles[x] = Tile::Wall; // Top edge
-            tiles[(height - 1) * width + x] = Tile::Wall; // Bottom edge
-        }
-        for y in 0..height {
-            tiles[y * width] = Tile::Wall; // Left edge
-            tiles[y * width + (width - 1)] = Tile::Wall; // Right edge
-        }
-        
-        // Create the classic Bomberman checkerboard wall pattern
-        // Every even row and even column (from inside border) gets a wall
-        for y in 1..height-1 {
-            for x in 1..width-1 {
-                // Check if this position should have a wall (checkerboard pattern)
-                let should_have_wall = (x % 2 == 0) && (y % 2 == 0);
-                
-                if should_have_wall {
-                    tiles[y * width + x] = Tile::Wall;
-                }
-            }
-        }
-        
-        // Fill remaining spaces with breakable blocks (SoftCrate)
-        for y in 1..height-1 {
-            for x in 1..width-1 {
-                let index = y * width + x;
-                
-                // Skip if this is already a wall
-                if tiles[index] == Tile::Wall {
-                    continue;
-                }
-                
-                // Fill with breakable blocks
-                tiles[index] = Tile::SoftCrate;
-            }
-        }
-        
-        // Clear spawn zones (3x3 areas) for bots
-        // These positions should be clear of both walls and breakable blocks
-        let spawn_positions = [
-            (3, 3),                    // Top-left
-            (width / 2, 3),            // Top-center
-            (width.saturating_sub(4), 3),            // Top-right
-            (3, height / 2),           // Middle-left
-            (width.saturating_sub(4), height / 2),   // Middle-right
-            (3, height.saturating_sub(4)),           // Bottom-left
-            (width / 2, height.saturating_sub(4)),   // Bottom-center
-            (width.saturating_sub(4), height.saturating_sub(4)),   // Bottom-right
-        ];
-        
-        for &(spawn_x, spawn_y) in &spawn_positions {
-            // Clear 3x3 area around spawn position
-            for dy in -1..=1 {
-                for dx in -1..=1 {
-                    let clear_x = spawn_x as i32 + dx;
-                    let clear_y = spawn_y as i32 + dy;
-                    
-                    if clear_x >= 0 && clear_x < width as i32 && 
-                       clear_y >= 0 && clear_y < height as i32 {
-                        let index = clear_y as usize * width + clear_x as usize;
-                        tiles[index] = Tile::Empty;
-                    }
-                }
-            }
-        }
-        
-        // Ensure connectivity by clearing some strategic paths
-        // Clear horizontal and vertical corridors every 4 tiles to maintain connectivity
-        for y in 1..height-1 {
-            for x in 1..width-1 {
-                let index = y * width + x;
-                
-                // Skip if this is already empty or a wall
-                if tiles[index] == Tile::Empty || tiles[index] == Tile::Wall {
-                    continue;
-                }
-                
-                // Create horizontal corridors every 4 tiles
-                if x % 4 == 0 {
-                    tiles[index] = Tile::Empty;
-                }
-                // Create vertical corridors every 4 tiles
-                else if y % 4 == 0 {
-                    tiles[index] = Tile::Empty;
-                }
-            }
-        }
-        
-        let bombs = Vec::new();
-        let agents = Vec::new();
-        let version = AtomicU64::new(0);
-        let (tx, _rx) = watch::channel(GridDelta::None);
-        let snapshot = Atomic::new(SnapshotInner::new(
-            Arc::<[Tile]>::from(tiles.clone()),
-            Arc::<[Bomb]>::from(bombs.clone()),
-            Arc::<[AgentState]>::from(agents.clone()),
-            version.load(Ordering::Relaxed),
-        ));
-        
-        Self {
-            width,
-            height,
-            tiles,
-            bombs,
-            agents,
-            version,
-            snapshot,
-            delta_tx: tx,
-        }
+        Self::from_layout(ClassicGenerator.layout(width, height))
+    }
+
+    /// Creates a grid from a generated layout, keeping its spawn points.
+    pub fn from_layout(layout: MapLayout) -> Self {
+        Self::from_parts(
+            layout.width,
+            layout.height,
+            layout.tiles,
+            Vec::new(),
+            Vec::new(),
+            layout.spawns,
+            0,
+        )
+    }
+
+    /// Creates a grid from a hand-authored plain-text layout, see
+    /// [`MapLayout::from_ascii`].
+    pub fn from_ascii(text: &str) -> Result<Self, MapError> {
+        MapLayout::from_ascii(text).map(Self::from_layout)
+    }
+
+    /// Creates a grid using `generator` with the given seed.
+    pub fn generate(
+        generator: &dyn MapGenerator,
+        width: usize,
+        height: usize,
+        seed: u64,
+    ) -> Result<Self, MapError> {
+        generator
+            .generate(width, height, seed)
+            .map(Self::from_layout)
     }
 
     /// Constructs a grid from raw parts used during deserialization.
@@ -156,6 +76,7 @@ impl GameGrid {
         tiles: Vec<Tile>,
         bombs: Vec<Bomb>,
         agents: Vec<AgentState>,
+        spawns: Vec<(u16, u16)>,
         version: u64,
     ) -> Self {
         let (tx, _rx) = watch::channel(GridDelta::None);
@@ -165,12 +86,15 @@ impl GameGrid {
             Arc::<[AgentState]>::from(agents.clone()),
             version,
         );
+        let next_bomb_id = bombs.iter().map(|b| b.id.0 + 1).max().unwrap_or(0);
         Self {
             width,
             height,
             tiles,
             bombs,
+            next_bomb_id,
             agents,
+            spawns,
             version: AtomicU64::new(version),
             snapshot: Atomic::new(inner),
             delta_tx: tx,
@@ -212,6 +136,11 @@ impl GameGrid {
         &self.agents
     }
 
+    /// Spawn points of the map in assignment order.
+    pub fn spawn_points(&self) -> &[(u16, u16)] {
+        &self.spawns
+    }
+
     fn index(&self, x: usize, y: usize) -> usize {
         y * self.width + x
     }
@@ -234,24 +163,38 @@ impl GameGrid {
         }
     }
 
-    /// Adds a bomb to the grid and returns its identifier.
+    /// Hand out an id no bomb on this grid has used yet.
+    pub fn allocate_bomb_id(&mut self) -> BombId {
+        let id = BombId(self.next_bomb_id);
+        self.next_bomb_id += 1;
+        id
+    }
+
+    /// Adds a bomb to the grid and returns its index.
     pub fn add_bomb(&mut self, bomb: Bomb) -> usize {
+        self.next_bomb_id = self.next_bomb_id.max(bomb.id.0 + 1);
         self.bombs.push(bomb);
         self.version.fetch_add(1, Ordering::Relaxed);
         self.bombs.len() - 1
     }
 
     /// Check if a bomb can be placed at `position`.
+    ///
+    /// The tile must be empty and free of other bombs.
     pub fn can_place_bomb(&self, position: (u16, u16)) -> bool {
         matches!(
             self.tile(position.0 as usize, position.1 as usize),
             Some(Tile::Empty)
-        )
+        ) && self.bombs.iter().all(|b| b.position != position)
     }
 
-    /// Place a bomb at `position` if possible.
-    pub fn place_bomb(&mut self, position: (u16, u16)) {
-        self.add_bomb(Bomb::new(0, position, 3, 1));
+    /// Place `bomb` at its position if possible, returning whether it was placed.
+    pub fn place_bomb(&mut self, bomb: Bomb) -> bool {
+        if !self.can_place_bomb(bomb.position) {
+            return false;
+        }
+        self.apply_delta(GridDelta::AddBomb(bomb));
+        true
     }
 
     /// Adds an agent to the grid and returns its identifier.
@@ -267,9 +210,20 @@ impl GameGrid {
             GridDelta::None => {}
             GridDelta::SetTile { x, y, tile } => self.set_tile(*x, *y, *tile),
             GridDelta::AddBomb(b) => {
+                self.next_bomb_id = self.next_bomb_id.max(b.id.0 + 1);
                 self.bombs.push(b.clone());
                 self.version.fetch_add(1, Ordering::Relaxed);
             }
+            GridDelta::UpdateBomb(b) => {
+                if let Some(bomb) = self.bombs.iter_mut().find(|bomb| bomb.id == b.id) {
+                    *bomb = b.clone();
+                    self.version.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+            GridDelta::RemoveBomb(id) => {
+                self.bombs.retain(|b| b.id != *id);
+                self.version.fetch_add(1, Ordering::Relaxed);
+            }
             GridDelta::AddAgent(a) => {
                 self.agents.push(a.clone());
                 self.version.fetch_add(1, Ordering::Relaxed);
@@ -280,6 +234,12 @@ impl GameGrid {
                     self.version.fetch_add(1, Ordering::Relaxed);
                 }
             }
+            GridDelta::UpdateAgent(a) => {
+                if let Some(agent) = self.agents.iter_mut().find(|agent| agent.id == a.id) {
+                    *agent = a.clone();
+                    self.version.fetch_add(1, Ordering::Relaxed);
+                }
+            }
             GridDelta::RemoveAgent(agent_id) => {
                 self.agents.retain(|a| a.id != *agent_id);
                 self.version.fetch_add(1, Ordering::Relaxed);
@@ -386,7 +346,7 @@ mod tests {
     #[test]
     fn add_entities_update_version() {
         let mut grid = GameGrid::new(1, 1);
-        grid.add_bomb(Bomb::new(0, (0, 0), 3, 1));
+        grid.add_bomb(Bomb::new(BombId(0), 0, (0, 0), 3, 1));
         assert_eq!(grid.version(), 1);
         grid.add_agent(AgentState::new(0, (0, 0)));
         assert_eq!(grid.version(), 2);
@@ -450,7 +410,33 @@ mod tests {
     #[test]
     fn place_bomb_adds_bomb() {
         let mut grid = GameGrid::new(1, 1);
-        grid.place_bomb((0, 0));
+        assert!(grid.place_bomb(Bomb::new(BombId(0), 0, (0, 0), 3, 1)));
+        assert_eq!(grid.bombs().len(), 1);
+    }
+
+    #[test]
+    fn place_bomb_rejects_occupied_tile() {
+        let mut grid = GameGrid::new(1, 1);
+        assert!(grid.place_bomb(Bomb::new(BombId(0), 0, (0, 0), 3, 1)));
+        assert!(!grid.can_place_bomb((0, 0)));
+        assert!(!grid.place_bomb(Bomb::new(BombId(1), 1, (0, 0), 5, 2)));
+        assert_eq!(grid.bombs().len(), 1);
+    }
+
+    #[test]
+    fn bombs_are_told_apart_by_id() {
+        let mut grid = GameGrid::new(3, 1);
+        for x in 0..3 {
+            grid.set_tile(x, 0, Tile::Empty);
+        }
+        grid.apply_delta(GridDelta::AddBomb(Bomb::new(BombId(4), 0, (0, 0), 3, 1)));
+        let id = grid.allocate_bomb_id();
+        assert_eq!(id, BombId(5));
+        assert!(grid.place_bomb(Bomb::new(id, 0, (2, 0), 3, 1)));
+        assert_ne!(grid.allocate_bomb_id(), id);
+
+        grid.apply_delta(GridDelta::RemoveBomb(BombId(4)));
         assert_eq!(grid.bombs().len(), 1);
+        assert_eq!(grid.bombs()[0].position, (2, 0));
     }
 }
@@ -0,0 +1,486 @@
+//! Map generation producing tile layouts and spawn points.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use super::tile::Tile;
+
+/// Tile layout and spawn points produced by a [`MapGenerator`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapLayout {
+    /// Width of the map.
+    pub width: usize,
+    /// Height of the map.
+    pub height: usize,
+    /// Tiles in row-major order.
+    pub tiles: Vec<Tile>,
+    /// Spawn points in assignment order.
+    pub spawns: Vec<(u16, u16)>,
+}
+
+impl MapLayout {
+    /// Tile at the given coordinates if within bounds.
+    pub fn tile(&self, x: usize, y: usize) -> Option<Tile> {
+        if x < self.width && y < self.height {
+            Some(self.tiles[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Whether every spawn point can reach every other one.
+    ///
+    /// Soft crates count as passable since they can be bombed away; walls
+    /// and tiles outside the map do not.
+    pub fn spawns_connected(&self) -> bool {
+        let Some(&(sx, sy)) = self.spawns.first() else {
+            return true;
+        };
+        let passable = |x: usize, y: usize| matches!(self.tile(x, y), Some(t) if t != Tile::Wall);
+        if !passable(sx as usize, sy as usize) {
+            return false;
+        }
+        let mut seen = vec![false; self.tiles.len()];
+        let mut queue = VecDeque::from([(sx as usize, sy as usize)]);
+        seen[sy as usize * self.width + sx as usize] = true;
+        while let Some((x, y)) = queue.pop_front() {
+            let neighbours = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+            for (nx, ny) in neighbours {
+                if passable(nx, ny) && !seen[ny * self.width + nx] {
+                    seen[ny * self.width + nx] = true;
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        self.spawns
+            .iter()
+            .all(|&(x, y)| seen[y as usize * self.width + x as usize])
+    }
+}
+
+/// Errors raised while generating or parsing a map.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapError {
+    /// The requested dimensions cannot hold a playable map.
+    TooSmall {
+        /// Requested width.
+        width: usize,
+        /// Requested height.
+        height: usize,
+    },
+    /// Eight-way symmetry requires a square map.
+    NotSquare,
+    /// Crate density must lie within `0.0..=1.0`.
+    InvalidDensity(f32),
+    /// Some spawn point cannot reach the others.
+    Disconnected,
+    /// A map file contains no rows.
+    EmptyLayout,
+    /// A map file row differs in length from the first row.
+    RaggedRow {
+        /// One-based row number.
+        row: usize,
+        /// Length of the first row.
+        expected: usize,
+        /// Length of this row.
+        found: usize,
+    },
+    /// A map file uses a symbol with no meaning.
+    UnknownSymbol {
+        /// The offending symbol.
+        symbol: char,
+        /// One-based row number.
+        row: usize,
+        /// One-based column number.
+        column: usize,
+    },
+    /// A spawn number appears more than once.
+    DuplicateSpawn(u8),
+    /// Spawn numbers skip this number.
+    MissingSpawn(u8),
+}
+
+impl fmt::Display for MapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapError::TooSmall { width, height } => {
+                write!(f, "map of {width}x{height} is too small")
+            }
+            MapError::NotSquare => write!(f, "eight-way symmetry requires a square map"),
+            MapError::InvalidDensity(d) => write!(f, "crate density {d} is outside 0.0..=1.0"),
+            MapError::Disconnected => write!(f, "spawn points are not connected"),
+            MapError::EmptyLayout => write!(f, "map layout has no rows"),
+            MapError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(f, "row {row} has {found} tiles, expected {expected}"),
+            MapError::UnknownSymbol {
+                symbol,
+                row,
+                column,
+            } => write!(f, "unknown symbol '{symbol}' at row {row}, column {column}"),
+            MapError::DuplicateSpawn(n) => write!(f, "spawn {n} appears more than once"),
+            MapError::MissingSpawn(n) => write!(f, "spawn {n} is missing"),
+        }
+    }
+}
+
+impl std::error::Error for MapError {}
+
+/// Produces map layouts for a given size and seed.
+pub trait MapGenerator {
+    /// Generate a layout; the same inputs always yield the same layout.
+    fn generate(&self, width: usize, height: usize, seed: u64) -> Result<MapLayout, MapError>;
+}
+
+/// Mirror symmetry applied to generated maps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Symmetry {
+    /// No mirroring.
+    None,
+    /// Mirrored across the vertical axis.
+    TwoWay,
+    /// Mirrored across both axes.
+    #[default]
+    FourWay,
+    /// Mirrored across both axes and both diagonals; needs a square map.
+    EightWay,
+}
+
+impl Symmetry {
+    /// Representative cell of the symmetry class containing `(x, y)`.
+    fn canonical(self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        let mx = x.min(width - 1 - x);
+        let my = y.min(height - 1 - y);
+        match self {
+            Symmetry::None => (x, y),
+            Symmetry::TwoWay => (mx, y),
+            Symmetry::FourWay => (mx, my),
+            Symmetry::EightWay => (mx.min(my), mx.max(my)),
+        }
+    }
+}
+
+/// The classic fixed layout: bordered checkerboard, crates everywhere else,
+/// corridors every four tiles and eight cleared 3x3 spawn pockets.
+///
+/// The seed is ignored.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ClassicGenerator;
+
+impl ClassicGenerator {
+    /// Build the classic layout; never fails.
+    pub fn layout(&self, width: usize, height: usize) -> MapLayout {
+        let mut tiles = vec![Tile::Empty; width * height];
+
+        // Add solid walls around the border
+        for x in 0..width {
+            tiles[x] = Tile::Wall; // Top edge
+            tiles[(height - 1) * width + x] = Tile::Wall; // Bottom edge
+        }
+        for y in 0..height {
+            tiles[y * width] = Tile::Wall; // Left edge
+            tiles[y * width + (width - 1)] = Tile::Wall; // Right edge
+        }
+
+        // Every even row and even column (from inside border) gets a wall,
+        // the remaining spaces are filled with breakable blocks
+        for y in 1..height.saturating_sub(1) {
+            for x in 1..width.saturating_sub(1) {
+                tiles[y * width + x] = if x % 2 == 0 && y % 2 == 0 {
+                    Tile::Wall
+                } else {
+                    Tile::SoftCrate
+                };
+            }
+        }
+
+        // Clear spawn zones (3x3 areas) for bots
+        let spawn_positions = [
+            (3, 3),                                              // Top-left
+            (width / 2, 3),                                      // Top-center
+            (width.saturating_sub(4), 3),                        // Top-right
+            (3, height / 2),                                     // Middle-left
+            (width.saturating_sub(4), height / 2),               // Middle-right
+            (3, height.saturating_sub(4)),                       // Bottom-left
+            (width / 2, height.saturating_sub(4)),               // Bottom-center
+            (width.saturating_sub(4), height.saturating_sub(4)), // Bottom-right
+        ];
+
+        for &(spawn_x, spawn_y) in &spawn_positions {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let clear_x = spawn_x as i32 + dx;
+                    let clear_y = spawn_y as i32 + dy;
+
+                    if clear_x >= 0
+                        && clear_x < width as i32
+                        && clear_y >= 0
+                        && clear_y < height as i32
+                    {
+                        tiles[clear_y as usize * width + clear_x as usize] = Tile::Empty;
+                    }
+                }
+            }
+        }
+
+        // Clear horizontal and vertical corridors every 4 tiles to maintain connectivity
+        for y in 1..height.saturating_sub(1) {
+            for x in 1..width.saturating_sub(1) {
+                let index = y * width + x;
+                if tiles[index] == Tile::SoftCrate && (x % 4 == 0 || y % 4 == 0) {
+                    tiles[index] = Tile::Empty;
+                }
+            }
+        }
+
+        let mut spawns: Vec<(u16, u16)> = Vec::new();
+        for &(x, y) in &spawn_positions {
+            let spawn = (x as u16, y as u16);
+            if x < width && y < height && !spawns.contains(&spawn) {
+                spawns.push(spawn);
+            }
+        }
+
+        MapLayout {
+            width,
+            height,
+            tiles,
+            spawns,
+        }
+    }
+}
+
+impl MapGenerator for ClassicGenerator {
+    fn generate(&self, width: usize, height: usize, _seed: u64) -> Result<MapLayout, MapError> {
+        Ok(self.layout(width, height))
+    }
+}
+
+/// Seeded generator scattering soft crates over a bordered checkerboard.
+///
+/// Spawns sit in the corners, plus the middle of each edge whose length is
+/// odd, each with its open neighbours cleared. Crates are drawn once per
+/// symmetry class so every spawn faces the same surroundings.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RandomGenerator {
+    /// Probability that a free tile holds a soft crate.
+    pub crate_density: f32,
+    /// Mirror symmetry of the layout.
+    pub symmetry: Symmetry,
+}
+
+impl Default for RandomGenerator {
+    fn default() -> Self {
+        Self {
+            crate_density: 0.7,
+            symmetry: Symmetry::FourWay,
+        }
+    }
+}
+
+impl RandomGenerator {
+    /// Smallest width or height the generator accepts.
+    pub const MIN_SIZE: usize = 5;
+
+    fn spawn_points(width: usize, height: usize) -> Vec<(u16, u16)> {
+        let (right, bottom) = (width - 2, height - 2);
+        let mut spawns = vec![(1, 1), (right, bottom), (right, 1), (1, bottom)];
+        if width % 2 == 1 {
+            spawns.push((width / 2, 1));
+            spawns.push((width / 2, bottom));
+        }
+        if height % 2 == 1 {
+            spawns.push((1, height / 2));
+            spawns.push((right, height / 2));
+        }
+        spawns
+            .into_iter()
+            .map(|(x, y)| (x as u16, y as u16))
+            .collect()
+    }
+}
+
+impl MapGenerator for RandomGenerator {
+    fn generate(&self, width: usize, height: usize, seed: u64) -> Result<MapLayout, MapError> {
+        if width < Self::MIN_SIZE || height < Self::MIN_SIZE {
+            return Err(MapError::TooSmall { width, height });
+        }
+        if self.symmetry == Symmetry::EightWay && width != height {
+            return Err(MapError::NotSquare);
+        }
+        if !(0.0..=1.0).contains(&self.crate_density) {
+            return Err(MapError::InvalidDensity(self.crate_density));
+        }
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut crates = vec![false; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                if self.symmetry.canonical(x, y, width, height) == (x, y) {
+                    crates[y * width + x] = rng.gen_bool(self.crate_density as f64);
+                }
+            }
+        }
+        for y in 0..height {
+            for x in 0..width {
+                let (cx, cy) = self.symmetry.canonical(x, y, width, height);
+                crates[y * width + x] = crates[cy * width + cx];
+            }
+        }
+
+        let mut tiles = vec![Tile::Empty; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                // Measure from the nearest border so walls mirror on even sizes too.
+                let (bx, by) = (x.min(width - 1 - x), y.min(height - 1 - y));
+                tiles[y * width + x] = if bx == 0 || by == 0 || (bx % 2 == 0 && by % 2 == 0) {
+                    Tile::Wall
+                } else if crates[y * width + x] {
+                    Tile::SoftCrate
+                } else {
+                    Tile::Empty
+                };
+            }
+        }
+
+        let spawns = Self::spawn_points(width, height);
+        for &(sx, sy) in &spawns {
+            let (sx, sy) = (sx as usize, sy as usize);
+            for (x, y) in [
+                (sx, sy),
+                (sx - 1, sy),
+                (sx + 1, sy),
+                (sx, sy - 1),
+                (sx, sy + 1),
+            ] {
+                if tiles[y * width + x] != Tile::Wall {
+                    tiles[y * width + x] = Tile::Empty;
+                }
+            }
+        }
+
+        let layout = MapLayout {
+            width,
+            height,
+            tiles,
+            spawns,
+        };
+        if !layout.spawns_connected() {
+            return Err(MapError::Disconnected);
+        }
+        Ok(layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mirrored(layout: &MapLayout, symmetry: Symmetry) -> bool {
+        let (w, h) = (layout.width, layout.height);
+        (0..h).all(|y| {
+            (0..w).all(|x| {
+                let (cx, cy) = symmetry.canonical(x, y, w, h);
+                layout.tile(x, y) == layout.tile(cx, cy)
+            })
+        })
+    }
+
+    #[test]
+    fn same_seed_same_layout() {
+        let generator = RandomGenerator::default();
+        let a = generator.generate(15, 13, 7).unwrap();
+        let b = generator.generate(15, 13, 7).unwrap();
+        let c = generator.generate(15, 13, 8).unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a.tiles, c.tiles);
+    }
+
+    #[test]
+    fn layouts_honour_symmetry() {
+        for symmetry in [Symmetry::TwoWay, Symmetry::FourWay, Symmetry::EightWay] {
+            let generator = RandomGenerator {
+                crate_density: 0.5,
+                symmetry,
+            };
+            for seed in 0..8 {
+                let layout = generator.generate(13, 13, seed).unwrap();
+                assert!(mirrored(&layout, symmetry), "{symmetry:?} seed {seed}");
+            }
+        }
+        let even = RandomGenerator::default().generate(12, 10, 3).unwrap();
+        assert!(mirrored(&even, Symmetry::FourWay));
+    }
+
+    #[test]
+    fn density_controls_crates() {
+        let count = |density| {
+            let generator = RandomGenerator {
+                crate_density: density,
+                symmetry: Symmetry::None,
+            };
+            let layout = generator.generate(15, 13, 1).unwrap();
+            layout
+                .tiles
+                .iter()
+                .filter(|t| **t == Tile::SoftCrate)
+                .count()
+        };
+        assert_eq!(count(0.0), 0);
+        assert!(count(0.2) < count(0.9));
+    }
+
+    #[test]
+    fn spawns_are_open_and_connected() {
+        let layout = RandomGenerator {
+            crate_density: 1.0,
+            symmetry: Symmetry::FourWay,
+        }
+        .generate(15, 13, 9)
+        .unwrap();
+        assert_eq!(layout.spawns.len(), 8);
+        for &(x, y) in &layout.spawns {
+            assert_eq!(layout.tile(x as usize, y as usize), Some(Tile::Empty));
+        }
+        assert!(layout.spawns_connected());
+    }
+
+    #[test]
+    fn walled_off_spawn_is_detected() {
+        let mut layout = ClassicGenerator.layout(13, 11);
+        let (x, y) = layout.spawns[0];
+        for (nx, ny) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+            layout.tiles[ny as usize * 13 + nx as usize] = Tile::Wall;
+        }
+        assert!(!layout.spawns_connected());
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        let generator = RandomGenerator {
+            crate_density: 0.5,
+            symmetry: Symmetry::EightWay,
+        };
+        assert_eq!(generator.generate(13, 11, 0), Err(MapError::NotSquare));
+        assert!(matches!(
+            RandomGenerator::default().generate(3, 9, 0),
+            Err(MapError::TooSmall { .. })
+        ));
+        let dense = RandomGenerator {
+            crate_density: 1.5,
+            symmetry: Symmetry::None,
+        };
+        assert_eq!(dense.generate(9, 9, 0), Err(MapError::InvalidDensity(1.5)));
+    }
+}
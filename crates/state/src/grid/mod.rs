@@ -1,12 +1,19 @@
 //! Grid related data structures.
 
+/// Plain-text map layouts.
+pub mod ascii;
 /// Delta enumeration for grid updates.
 pub mod delta;
 /// Grid implementation and helpers.
 pub mod game_grid;
+/// Map layout generators.
+pub mod generator;
 /// Tile enumeration.
 pub mod tile;
 
 pub use delta::GridDelta;
 pub use game_grid::{GameGrid, ObservationDelta};
+pub use generator::{
+    ClassicGenerator, MapError, MapGenerator, MapLayout, RandomGenerator, Symmetry,
+};
 pub use tile::Tile;
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::components::PowerUpType;
+
 /// Different types of grid tiles.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Tile {
@@ -11,8 +13,8 @@ pub enum Tile {
     Wall,
     /// Destructible crate
     SoftCrate,
-    /// Tile containing a power-up
-    PowerUp,
+    /// Tile containing a power-up of the given kind
+    PowerUp(PowerUpType),
     /// Explosion animation tile (temporary)
     Explosion,
 }
@@ -24,7 +26,7 @@ impl Tile {
             Tile::Empty => 0,
             Tile::Wall => 1,
             Tile::SoftCrate => 2,
-            Tile::PowerUp => 3,
+            Tile::PowerUp(_) => 3,
             Tile::Explosion => 4,
         }
     }
@@ -9,8 +9,11 @@ pub mod grid;
 pub mod serialization;
 pub mod state;
 
-pub use components::{AgentState, Bomb};
-pub use grid::{GameGrid, ObservationDelta, Tile};
+pub use components::{AgentState, Bomb, BombId, PowerUpType};
+pub use grid::{
+    ClassicGenerator, GameGrid, MapError, MapGenerator, MapLayout, ObservationDelta,
+    RandomGenerator, Symmetry, Tile,
+};
 pub use serialization::{Format, SerializationError, decoder, encoder};
 pub use state::{GameState, SnapshotView};
 
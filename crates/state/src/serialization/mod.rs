@@ -46,6 +46,7 @@ pub(crate) struct SerializableState {
     tiles: Vec<Tile>,
     bombs: Vec<Bomb>,
     agents: Vec<AgentState>,
+    spawns: Vec<(u16, u16)>,
     version: u64,
 }
 
@@ -57,6 +58,7 @@ impl From<&GameState> for SerializableState {
             tiles: state.grid.tiles().to_vec(),
             bombs: state.grid.bombs().to_vec(),
             agents: state.grid.agents().to_vec(),
+            spawns: state.grid.spawn_points().to_vec(),
             version: state.grid.version(),
         }
     }
@@ -64,7 +66,9 @@ impl From<&GameState> for SerializableState {
 
 impl From<SerializableState> for GameState {
     fn from(s: SerializableState) -> Self {
-        let grid = GameGrid::from_parts(s.width, s.height, s.tiles, s.bombs, s.agents, s.version);
+        let grid = GameGrid::from_parts(
+            s.width, s.height, s.tiles, s.bombs, s.agents, s.spawns, s.version,
+        );
         GameState { grid }
     }
 }
@@ -78,7 +82,7 @@ pub mod encoder;
 mod tests {
     use super::{Format, decoder, encoder};
     use crate::{
-        components::{AgentState, Bomb},
+        components::{AgentState, Bomb, BombId},
         grid::{GridDelta, Tile},
         state::GameState,
     };
@@ -91,7 +95,7 @@ mod tests {
             y: 1,
             tile: Tile::Wall,
         });
-        state.apply_delta(GridDelta::AddBomb(Bomb::new(1, (0, 0), 3, 1)));
+        state.apply_delta(GridDelta::AddBomb(Bomb::new(BombId(0), 1, (0, 0), 3, 1)));
         state.apply_delta(GridDelta::AddAgent(AgentState::new(1, (1, 1))));
 
         for format in [Format::Binary, Format::Json] {